@@ -10,6 +10,9 @@ use darling::{FromField, FromDeriveInput};
 struct StructArgs {
 	#[darling(default)]
 	no_default: bool,
+	/// Clone the component for the header's copy and paste
+	#[darling(default)]
+	copy: bool,
 }
 
 #[derive(Debug, FromField, Default)]
@@ -18,6 +21,13 @@ struct FieldArgs {
 	null_to: Option<syn::Lit>,
 	speed: Option<f32>,
 	skip: bool,
+	multiline: bool,
+	max_length: Option<usize>,
+	hint: Option<String>,
+	unit: Option<String>,
+	color: bool,
+	angle: bool,
+	snap: bool,
 	#[darling(multiple)]
 	with_component: Vec<syn::Path>,
 }
@@ -25,17 +35,18 @@ struct FieldArgs {
 #[proc_macro_derive(Inspect, attributes(inspect))]
 pub fn derive_inspect(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
-	let no_default = StructArgs::from_derive_input(&input).unwrap().no_default;
+	let StructArgs { no_default, copy } = StructArgs::from_derive_input(&input).unwrap();
 
 	let name = input.ident;
 	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-	let (inspect, extra_data) = inspect(&input.data, &name);
+	let (inspect, extra_data, state) = inspect(&input.data, &name, no_default);
 	let (can_add, add) = match (no_default, input.data) {
 		(_, Data::Struct(DataStruct { fields: Fields::Unit, .. })) => (true, quote!(lazy.insert(entity, Self);)),
 		(false, _) => (true, quote!(lazy.insert(entity, Self::default());)),
 		(true, _) => (false, quote!({})),
 	};
+	let copy = if copy { Some(quote!(fn copy(&self) -> Option<Self> { Some(::std::clone::Clone::clone(self)) })) } else { None };
 
 	let expanded = quote! {
 		impl<'a> #impl_generics ::amethyst_inspector::Inspect<'a> for #name #ty_generics #where_clause {
@@ -44,21 +55,61 @@ pub fn derive_inspect(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 				::amethyst::ecs::ReadStorage<'a, Self>,
 				#extra_data
 			);
+			type State = #state;
 
 			#inspect
 			fn add((lazy, ..): &mut Self::SystemData, entity: ::amethyst::ecs::Entity) { #add }
 			fn can_add(_: &mut Self::SystemData, _: ::amethyst::ecs::Entity) -> bool { #can_add }
+			#copy
 		}
 	};
 
 	proc_macro::TokenStream::from(expanded)
 }
 
-fn inspect(data: &Data, name: &Ident) -> (TokenStream, TokenStream) {
+/// Builder calls generated from field attributes, each control only needs the methods for attributes it supports
+fn modifiers(args: &FieldArgs) -> TokenStream {
+	let null_to = args.null_to.as_ref().map(|x| quote!(.null_to(#x)));
+	let speed = args.speed.map(|x| quote!(.speed(#x)));
+	let multiline = if args.multiline { Some(quote!(.multiline(true))) } else { None };
+	let color = if args.color { Some(quote!(.color())) } else { None };
+	let angle = if args.angle { Some(quote!(.angle())) } else { None };
+	let snap = if args.snap { Some(quote!(.snap())) } else { None };
+	let max_length = args.max_length.map(|x| quote!(.max_length(#x)));
+	let hint = args.hint.as_ref().map(|x| quote!(.hint(&::amethyst_imgui::imgui::im_str!("{}", #x))));
+	let unit = args.unit.as_ref().map(|x| {
+		let unit = match x.as_str() {
+			"ns" => quote!(Nanos),
+			"us" | "µs" => quote!(Micros),
+			"ms" => quote!(Millis),
+			"s" => quote!(Secs),
+			"min" => quote!(Mins),
+			_ => panic!("unknown duration unit `{}`, expected one of ns, us, ms, s, min", x),
+		};
+		quote!(.unit(::amethyst_inspector::DurationUnit::#unit))
+	});
+
+	// color and angle turn the builder into a different one so they go first
+	quote!(#color #angle #null_to #speed #multiline #max_length #hint #unit #snap)
+}
+
+fn inspect(data: &Data, name: &Ident, no_default: bool) -> (TokenStream, TokenStream, TokenStream) {
 	match *data {
 		Data::Struct(ref data) => {
 			match data.fields {
 				Fields::Named(ref fields) => {
+					// fields drawn with a control, in the order their states are in `Self::State`
+					let controlled = fields.named.iter().filter(|f| {
+						let args = FieldArgs::from_field(&f).unwrap();
+						!args.skip && args.with_component.is_empty()
+					}).collect::<Vec<_>>();
+					let state_types = controlled.iter().map(|f| {
+						let ty = &f.ty;
+						quote!(<&'a mut #ty as ::amethyst_inspector::InspectControl<'a, 'a>>::State,)
+					});
+					let state = quote!((#(#state_types)*));
+					let state_arg = if controlled.is_empty() { quote!(_) } else { quote!(state) };
+
 					let inspect_fields = fields.named.iter().map(|f| {
 						let args = FieldArgs::from_field(&f).unwrap();
 						let skip = args.skip;
@@ -73,17 +124,18 @@ fn inspect(data: &Data, name: &Ident) -> (TokenStream, TokenStream) {
 							return with_component_body(f.ident.as_ref().unwrap(), varname, &args.with_component);
 						}
 
-						// TODO: more field attrs
-						let null_to = args.null_to.map(|x| quote!(.null_to(#x))).unwrap_or(quote!());
-						let speed = args.speed.map(|x| quote!(.speed(#x))).unwrap_or(quote!());
+						let modifiers = modifiers(&args);
+						let default_to = if no_default { None } else { Some(quote!(.default_to(&defaults.#name))) };
+						let index = syn::Index::from(controlled.iter().position(|x| x.ident == f.ident).unwrap());
 
 						quote!{
 							let mut #name = me.#name.clone();
 							<&mut #ty as ::amethyst_inspector::InspectControl>::control(&mut #name)
 								.changed(&mut changed)
 								.data(#varname)
-								#null_to
-								#speed
+								.state(&mut state.#index)
+								#modifiers
+								#default_to
 								.label(&::amethyst_imgui::imgui::im_str!("{}", stringify!(#name)))
 								.build();
 						}
@@ -119,14 +171,16 @@ fn inspect(data: &Data, name: &Ident) -> (TokenStream, TokenStream) {
 						let varname = syn::Ident::new(&storage, f.span());
 						quote!{#varname, }
 					});
+					let defaults = if no_default { None } else { Some(quote!(let defaults = <Self as Default>::default();)) };
 					(quote! {
-						fn inspect((lazy, storage, #(#extra_data_members)*): &mut Self::SystemData, entity: ::amethyst::ecs::Entity) {
+						fn inspect((lazy, storage, #(#extra_data_members)*): &mut Self::SystemData, #state_arg: &mut Self::State, entity: ::amethyst::ecs::Entity) {
 							use ::amethyst_inspector::InspectControlBuilder;
 
 							::amethyst_imgui::with(|ui| {
 								let me = if let Some(x) = storage.get(entity) { x } else { return; };
 								let mut changed = false;
 								let id = ui.push_id(&::amethyst_imgui::imgui::im_str!("{}", stringify!(#name)));
+								#defaults
 
 								#(#inspect_fields)*
 
@@ -140,9 +194,9 @@ fn inspect(data: &Data, name: &Ident) -> (TokenStream, TokenStream) {
 								id.pop(ui);
 							});
 						}
-					}, quote!{#(#extra_data)*})
+					}, quote!{#(#extra_data)*}, state)
 				}
-				Fields::Unit => { (quote!(), quote!()) },
+				Fields::Unit => { (quote!(), quote!(), quote!(())) },
 				_ => unimplemented!(),
 			}
 		},
@@ -230,6 +284,22 @@ pub fn derive_inspect_control(input: proc_macro::TokenStream) -> proc_macro::Tok
 		_ => unimplemented!(),
 	};
 
+	let shown = match &input.data {
+		Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => {
+			fields.named.iter().filter(|f| !FieldArgs::from_field(&f).unwrap().skip).map(|f| f.ident.clone()).collect::<Vec<_>>()
+		},
+		_ => unimplemented!(),
+	};
+	let state = match &input.data {
+		Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => {
+			fields.named.iter().filter(|f| !FieldArgs::from_field(&f).unwrap().skip).map(|f| {
+				let ty = &f.ty;
+				quote!(<&'control mut #ty as ::amethyst_inspector::InspectControl<'control, 'resource>>::State,)
+			}).collect::<Vec<_>>()
+		},
+		_ => unimplemented!(),
+	};
+
 	let control = match input.data {
 		Data::Struct(data) => {
 			match data.fields {
@@ -246,17 +316,16 @@ pub fn derive_inspect_control(input: proc_macro::TokenStream) -> proc_macro::Tok
 						//     return with_component_body(f.ident.as_ref().unwrap(), varname, &args.with_component);
 						// }
 
-						// TODO: more field attrs
-						let null_to = args.null_to.map(|x| quote!(.null_to(#x))).unwrap_or(quote!());
-						let speed = args.speed.map(|x| quote!(.speed(#x))).unwrap_or(quote!());
+						let modifiers = modifiers(&args);
 
 						let index = syn::Index::from(i);
+						let state_index = syn::Index::from(shown.iter().position(|x| *x == f.ident).unwrap());
 						quote! {
 							<&mut #ty as ::amethyst_inspector::InspectControl>::control(&mut self.value.#name)
 								.changed(&mut changed)
 								.data(&mut data.#index)
-								#null_to
-								#speed
+								.state(&mut state.#state_index)
+								#modifiers
 								.label(&::amethyst_imgui::imgui::im_str!("{}", stringify!(#name)))
 								.build();
 						}
@@ -274,17 +343,23 @@ pub fn derive_inspect_control(input: proc_macro::TokenStream) -> proc_macro::Tok
 			pub value: &'control mut #name,
 			pub data: Option<&'control mut <&'control mut #name as ::amethyst_inspector::InspectControl<'control, 'resource>>::SystemData>,
 			pub label: Option<&'control ::amethyst_imgui::imgui::ImStr>,
+			pub state: Option<&'control mut <&'control mut #name as ::amethyst_inspector::InspectControl<'control, 'resource>>::State>,
 			pub changed: Option<&'control mut bool>,
 		}
 
 		impl<'control, 'resource: 'control> #impl_generics ::amethyst_inspector::InspectControl<'control, 'resource> for &'control mut #name #ty_generics #where_clause {
 			type SystemData = (#(#system_data),*);
+			type State = (#(#state)*);
 			type Builder = #builder<'control, 'resource>;
 		}
 
 		impl<'control, 'resource: 'control> ::amethyst_inspector::InspectControlBuilder<'control, 'resource, &'control mut #name> for #builder<'control, 'resource> {
 			fn new(value: &'control mut #name) -> Self {
-				Self { value, label: None, changed: None, data: None }
+				Self { value, label: None, state: None, changed: None, data: None }
+			}
+			fn state(mut self, state: &'control mut <&'control mut #name as ::amethyst_inspector::InspectControl<'control, 'resource>>::State) -> Self {
+				self.state = Some(state);
+				self
 			}
 			fn label(mut self, label: &'control ::amethyst_imgui::imgui::ImStr) -> Self {
 				self.label = Some(label);
@@ -301,6 +376,8 @@ pub fn derive_inspect_control(input: proc_macro::TokenStream) -> proc_macro::Tok
 			fn build(mut self) {
 				let mut changed = false;
 				let mut data = self.data.take().unwrap();
+				let mut fallback = Default::default();
+				let state = match self.state.take() { Some(state) => state, None => &mut fallback };
 
 				::amethyst_imgui::with(|ui| {
 					ui.tree_node(&::amethyst_imgui::imgui::im_str!("{}", stringify!(#name))).selected(true).build(|| {
@@ -13,13 +13,10 @@ use amethyst::{
 		Transform,
 		Named,
 	},
-	ui::{UiTransform, UiText},
+	ui::{UiImage, UiTransform, UiText},
 };
 
-use amethyst_inspector::{inspector, InspectControl, Inspect};
-
-type TextureList = std::collections::HashMap<String, amethyst::assets::Handle<amethyst::renderer::Texture>>;
-type SpriteList = std::collections::HashMap<String, amethyst::assets::Handle<amethyst::renderer::SpriteSheet>>;
+use amethyst_inspector::{inspector, FontList, InspectControl, Inspect, SpriteList, TextureList, UiTransformDebug};
 
 struct Example;
 impl SimpleState for Example {
@@ -28,7 +25,7 @@ impl SimpleState for Example {
 
 		world.setup::<(
 			Read<'_, SpriteList>,
-			Read<'_, std::collections::HashMap<String, amethyst::ui::FontHandle>>,
+			Read<'_, FontList>,
 			Read<'_, TextureList>,
 		)>();
 
@@ -54,6 +51,7 @@ pub struct Movement {
 }
 
 #[derive(Clone, Inspect)]
+#[inspect(copy)]
 pub struct Player {
 	pub movement: Movement,
 	pub direction: Vector2<f32>,
@@ -84,7 +82,9 @@ inspector![
 	Player,
 	Transparent,
 	UiTransform,
+	UiTransformDebug,
 	UiText,
+	UiImage,
 	SpriteRender,
 	Hidden,
 	HiddenPropagate,
@@ -106,6 +106,7 @@ fn main() -> amethyst::Result<()> {
 				)
 				.with_plugin(amethyst::renderer::plugins::RenderFlat2D::default())
 				.with_plugin(amethyst::ui::RenderUi::default())
+				.with_plugin(amethyst::renderer::plugins::RenderDebugLines::default())
 				.with_plugin(amethyst_imgui::RenderImgui::<amethyst::input::StringBindings>::default()),
 		)?
 		.with(amethyst_inspector::InspectorHierarchy::default(), "", &[])
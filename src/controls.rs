@@ -1,3 +1,31 @@
 pub mod vectors;
 pub mod numbers;
 pub mod misc;
+pub mod collections;
+pub mod maps;
+pub mod text;
+pub mod colors;
+pub mod geometry;
+pub mod angles;
+pub mod ranges;
+pub mod flags;
+pub mod assets;
+pub mod context;
+pub mod expression;
+pub mod snap;
+
+use amethyst_imgui::imgui::{self, im_str};
+
+/// Label to draw a control with, controls built without one get a hidden label unique to the value so their ids don't clash
+pub(crate) fn label_or_id<T: ?Sized>(label: Option<&imgui::ImStr>, value: &T) -> imgui::ImString {
+	match label {
+		Some(label) => im_str!("{}", label.to_str()),
+		None => im_str!("##{:p}", value),
+	}
+}
+
+/// Part of the label imgui would show, everything from `##` on only goes into the id
+pub(crate) fn visible_label(label: &imgui::ImStr) -> &str {
+	let label = label.to_str();
+	label.find("##").map_or(label, |i| &label[.. i])
+}
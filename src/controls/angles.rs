@@ -0,0 +1,110 @@
+use crate::{controls::{numbers, snap}, prelude::*};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// shared by every angle field, flipping one flips them all, whichever thread or system draws them
+static ANGLE_RADIANS: AtomicBool = AtomicBool::new(false);
+
+pub struct AngleControlBuilder<'control, T> {
+	pub value: &'control mut T,
+	pub label: Option<&'control imgui::ImStr>,
+	/// Degrees per pixel, converted when editing in radians
+	pub speed: f32,
+	pub null_to: T,
+	pub default: Option<T>,
+	pub wrap: Option<(T, T)>,
+	pub snap: Option<T>,
+	pub changed: Option<&'control mut bool>,
+}
+
+macro_rules! angles {
+	($($type:ident),+$(,)*) => {$(
+		impl<'control> numbers::$type::Builder<'control> {
+			/// Treat the value as an angle in radians, shown in degrees unless the user switches to radians
+			pub fn angle(self) -> AngleControlBuilder<'control, $type> {
+				AngleControlBuilder {
+					value: self.value,
+					label: self.label,
+					speed: 0.25,
+					null_to: self.null_to,
+					default: self.default,
+					wrap: Some((-std::$type::consts::PI, std::$type::consts::PI)),
+					snap: None,
+					changed: self.changed,
+				}
+			}
+		}
+
+		impl<'control> AngleControlBuilder<'control, $type> {
+			pub fn label(mut self, label: &'control imgui::ImStr) -> Self {
+				self.label = Some(label);
+				self
+			}
+			pub fn changed(mut self, changed: &'control mut bool) -> Self {
+				self.changed = Some(changed);
+				self
+			}
+			pub fn speed(mut self, speed: f32) -> Self {
+				self.speed = speed;
+				self
+			}
+			pub fn null_to(mut self, null_to: $type) -> Self {
+				self.null_to = null_to;
+				self
+			}
+			pub fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+				self.default = default.downcast_ref::<$type>().cloned();
+				self
+			}
+			/// Range in radians the angle is wrapped into, `-PI ..= PI` by default
+			pub fn wrap(mut self, min: $type, max: $type) -> Self {
+				assert!(max > min, "angle wrap range {} .. {} is empty", min, max);
+				self.wrap = Some((min, max));
+				self
+			}
+			pub fn no_wrap(mut self) -> Self {
+				self.wrap = None;
+				self
+			}
+			/// Round to multiples of this many radians
+			pub fn snap(mut self, increment: $type) -> Self {
+				self.snap = Some(increment);
+				self
+			}
+			pub fn build(self) {
+				amethyst_imgui::with(|ui| {
+					let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+					let id = ui.push_id(label);
+					let mut radians = ANGLE_RADIANS.load(Ordering::Relaxed);
+
+					let mut v = if radians { *self.value as f32 } else { self.value.to_degrees() as f32 };
+					let (speed, format) = if radians { (self.speed.to_radians(), im_str!("%.4f rad")) } else { (self.speed, im_str!("%.2f deg")) };
+					let mut changed = ui.drag_float(label, &mut v).speed(speed).display_format(format).build();
+					if changed {
+						let mut new = if radians { v as $type } else { (v as $type).to_radians() };
+						if let Some(snap) = self.snap {
+							new = snap::round(&*self.value as *const _ as usize, f64::from(*self.value), f64::from(new), f64::from(snap)) as $type;
+						}
+						if let Some((min, max)) = self.wrap {
+							let range = max - min;
+							new = ((new - min) % range + range) % range + min;
+						}
+						*self.value = new;
+					}
+					let hovered = ui.is_item_hovered();
+
+					ui.same_line(0.);
+					if ui.small_button(if radians { im_str!("rad") } else { im_str!("deg") }) {
+						radians = !radians;
+						ANGLE_RADIANS.store(radians, Ordering::Relaxed);
+					}
+
+					id.pop(ui);
+					changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &self.null_to, self.default.as_ref()) || changed;
+					if let Some(x) = self.changed { *x = *x || changed };
+				});
+			}
+		}
+	)+};
+}
+
+angles![f32, f64];
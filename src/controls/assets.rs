@@ -0,0 +1,126 @@
+use crate::prelude::*;
+use amethyst::assets::{Asset, AssetStorage, Handle};
+use std::{
+	collections::HashMap,
+	sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Add this as a resource and insert your handles into it to get a dropdown for `Handle<A>` selection
+pub struct AssetList<A: Asset> {
+	pub handles: HashMap<String, Handle<A>>,
+	requested: AtomicBool,
+	scanned: AtomicBool,
+}
+
+impl<A: Asset> Default for AssetList<A> {
+	fn default() -> Self { AssetList { handles: HashMap::new(), requested: AtomicBool::new(false), scanned: AtomicBool::new(false) } }
+}
+
+impl<A: Asset> std::ops::Deref for AssetList<A> {
+	type Target = HashMap<String, Handle<A>>;
+
+	fn deref(&self) -> &Self::Target { &self.handles }
+}
+
+impl<A: Asset> std::ops::DerefMut for AssetList<A> {
+	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.handles }
+}
+
+impl<A: Asset> AssetList<A> {
+	/// Name the handle is listed under, if it's listed at all
+	pub fn name_of(&self, handle: &Handle<A>) -> Option<&str> {
+		self.handles.iter().find(|(_, x)| *x == handle).map(|(key, _)| key.as_str())
+	}
+
+	/// Ask `AssetScanner` (if there is one) to fill this list, done whenever a picker for it is opened
+	///
+	/// Cheap to call every frame, the list is only scanned the first time. Use `rescan` to pick up new files.
+	pub fn request(&self) {
+		if !self.scanned.load(Ordering::Relaxed) {
+			self.requested.store(true, Ordering::Relaxed);
+		}
+	}
+
+	/// Scan the directory again on the next request, for files added since the last scan
+	pub fn rescan(&self) { self.scanned.store(false, Ordering::Relaxed); }
+
+	/// Whether the list was asked for and hasn't been scanned yet, counts as scanned from then on
+	pub fn take_request(&self) -> bool {
+		let requested = self.requested.swap(false, Ordering::Relaxed);
+		if requested {
+			self.scanned.store(true, Ordering::Relaxed);
+		}
+		requested
+	}
+}
+
+impl<'control, 'resource: 'control, A: Asset> InspectControl<'control, 'resource> for &'control mut Handle<A> {
+	type SystemData = (Read<'resource, AssetList<A>>, ReadExpect<'resource, AssetStorage<A>>);
+	/// Search filter
+	type State = imgui::ImString;
+	type Builder = HandleControlBuilder<'control, 'resource, A>;
+}
+
+pub struct HandleControlBuilder<'control, 'resource: 'control, A: Asset> {
+	pub value: &'control mut Handle<A>,
+	pub data: Option<&'control mut (Read<'resource, AssetList<A>>, ReadExpect<'resource, AssetStorage<A>>)>,
+	pub label: Option<&'control imgui::ImStr>,
+	pub state: Option<&'control mut imgui::ImString>,
+	pub changed: Option<&'control mut bool>,
+}
+
+impl<'control, 'resource: 'control, A: Asset> InspectControlBuilder<'control, 'resource, &'control mut Handle<A>> for HandleControlBuilder<'control, 'resource, A> {
+	fn new(value: &'control mut Handle<A>) -> Self {
+		Self { value, data: None, label: None, state: None, changed: None }
+	}
+	fn state(mut self, state: &'control mut imgui::ImString) -> Self {
+		self.state = Some(state);
+		self
+	}
+	fn data(mut self, data: &'control mut (Read<'resource, AssetList<A>>, ReadExpect<'resource, AssetStorage<A>>)) -> Self {
+		self.data = Some(data);
+		self
+	}
+	fn label(mut self, label: &'control imgui::ImStr) -> Self {
+		self.label = Some(label);
+		self
+	}
+	fn changed(mut self, changed: &'control mut bool) -> Self {
+		self.changed = Some(changed);
+		self
+	}
+	fn build(self) {
+		amethyst_imgui::with(|ui| {
+			let mut changed = false;
+			let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+			let (list, storage) = &**self.data.unwrap_or_else(f!());
+			let value = &mut *self.value;
+			let mut fallback = imgui::ImString::with_capacity(32);
+			let filter = match self.state { Some(state) => state, None => &mut fallback };
+
+			let mut items = list.iter().collect::<Vec<_>>();
+			items.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+			let loading = |handle: &Handle<A>| if storage.get(handle).is_some() { "" } else { " (loading)" };
+			let preview = im_str!("{}{}", list.name_of(value).unwrap_or("<unlisted>"), loading(value));
+
+			imgui::ComboBox::new(label).preview_value(&preview).build(ui, || {
+				list.request();
+				ui.input_text(im_str!("##search"), filter).resize_buffer(true).build();
+				let needle = filter.to_str().to_lowercase();
+
+				for (key, handle) in items.iter() {
+					if !key.to_lowercase().contains(&needle) {
+						continue;
+					}
+					if imgui::Selectable::new(&im_str!("{}{}", key, loading(handle))).selected(**handle == *value).build(ui) {
+						*value = (*handle).clone();
+						changed = true;
+					}
+				}
+			});
+
+			if let Some(x) = self.changed { *x = *x || changed };
+		});
+	}
+}
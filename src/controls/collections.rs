@@ -0,0 +1,239 @@
+use crate::prelude::*;
+use std::collections::VecDeque;
+
+enum Action {
+	Insert(usize),
+	Duplicate(usize),
+	Remove(usize),
+	Swap(usize, usize),
+}
+
+macro_rules! sequences {
+	($($container:ident),+$(,)*) => {$(paste::item!{
+		impl<'control, 'resource: 'control, T> InspectControl<'control, 'resource> for &'control mut $container<T>
+		where
+			T: Clone + Default + 'control,
+			for<'c> &'c mut T: InspectControl<'c, 'resource, SystemData = <&'control mut T as InspectControl<'control, 'resource>>::SystemData, State = <&'control mut T as InspectControl<'control, 'resource>>::State>,
+		{
+			type SystemData = <&'control mut T as InspectControl<'control, 'resource>>::SystemData;
+			/// States of the elements
+			type State = Vec<<&'control mut T as InspectControl<'control, 'resource>>::State>;
+			type Builder = [<$container ControlBuilder>]<'control, T, Self::SystemData, <&'control mut T as InspectControl<'control, 'resource>>::State>;
+		}
+
+		pub struct [<$container ControlBuilder>]<'control, T, D, S> {
+			pub value: &'control mut $container<T>,
+			pub data: Option<&'control mut D>,
+			pub state: Option<&'control mut Vec<S>>,
+			pub label: Option<&'control imgui::ImStr>,
+			pub changed: Option<&'control mut bool>,
+			pub reorderable: bool,
+		}
+
+		impl<'control, 'resource: 'control, T> InspectControlBuilder<'control, 'resource, &'control mut $container<T>> for [<$container ControlBuilder>]<'control, T, <&'control mut T as InspectControl<'control, 'resource>>::SystemData, <&'control mut T as InspectControl<'control, 'resource>>::State>
+		where
+			T: Clone + Default + 'control,
+			for<'c> &'c mut T: InspectControl<'c, 'resource, SystemData = <&'control mut T as InspectControl<'control, 'resource>>::SystemData, State = <&'control mut T as InspectControl<'control, 'resource>>::State>,
+		{
+			fn new(value: &'control mut $container<T>) -> Self {
+				Self { value, data: None, label: None, state: None, changed: None, reorderable: true }
+			}
+			fn state(mut self, state: &'control mut Vec<<&'control mut T as InspectControl<'control, 'resource>>::State>) -> Self {
+				self.state = Some(state);
+				self
+			}
+			fn data(mut self, data: &'control mut <&'control mut T as InspectControl<'control, 'resource>>::SystemData) -> Self {
+				self.data = Some(data);
+				self
+			}
+			fn label(mut self, label: &'control imgui::ImStr) -> Self {
+				self.label = Some(label);
+				self
+			}
+			fn changed(mut self, changed: &'control mut bool) -> Self {
+				self.changed = Some(changed);
+				self
+			}
+			fn build(self) {
+				amethyst_imgui::with(|ui| {
+					let mut changed = false;
+					let mut action = None;
+					let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+					let reorderable = self.reorderable;
+					let value = &mut *self.value;
+					let mut data = self.data;
+					let mut fallback = Vec::new();
+					let states = match self.state { Some(state) => state, None => &mut fallback };
+					states.resize_with(value.len(), Default::default);
+
+					ui.tree_node(label).label(&im_str!("{} [{}]", label, value.len())).build(|| {
+						let len = value.len();
+						for i in 0 .. len {
+							let id = ui.push_id(i as i32);
+
+							if reorderable {
+								ui.small_button(im_str!("="));
+								if ui.is_item_active() && !ui.is_item_hovered() {
+									let delta = ui.mouse_drag_delta(imgui::MouseButton::Left)[1];
+									let target = if delta < 0. { i.checked_sub(1) } else { Some(i + 1).filter(|&x| x < len) };
+									if let Some(target) = target {
+										action = Some(Action::Swap(i, target));
+										ui.reset_mouse_drag_delta(imgui::MouseButton::Left);
+									}
+								}
+								ui.same_line(0.);
+							}
+							if ui.small_button(im_str!("ins")) {
+								action = Some(Action::Insert(i));
+							}
+							ui.same_line(0.);
+							if ui.small_button(im_str!("dup")) {
+								action = Some(Action::Duplicate(i));
+							}
+							ui.same_line(0.);
+							if ui.small_button(im_str!("del")) {
+								action = Some(Action::Remove(i));
+							}
+							ui.same_line(0.);
+
+							let control = (&mut value[i]).control().changed(&mut changed).label(&im_str!("[{}]", i)).state(&mut states[i]);
+							if let Some(data) = data.as_mut() {
+								control.data(&mut **data).build();
+							} else {
+								control.build();
+							}
+
+							id.pop(ui);
+						}
+
+						if ui.small_button(im_str!("add")) {
+							action = Some(Action::Insert(len));
+						}
+					});
+
+					// states move along with their elements, copies start out fresh
+					match action {
+						Some(Action::Insert(i)) => {
+							value.insert(i, T::default());
+							states.insert(i, Default::default());
+						},
+						Some(Action::Duplicate(i)) => {
+							let copy = value[i].clone();
+							value.insert(i + 1, copy);
+							states.insert(i + 1, Default::default());
+						},
+						Some(Action::Remove(i)) => {
+							value.remove(i);
+							states.remove(i);
+						},
+						Some(Action::Swap(a, b)) => {
+							value.swap(a, b);
+							states.swap(a, b);
+						},
+						None => {},
+					}
+					changed = changed || action.is_some();
+
+					if let Some(x) = self.changed { *x = *x || changed };
+				});
+			}
+		}
+
+		impl<'control, T, D, S> [<$container ControlBuilder>]<'control, T, D, S> {
+			pub fn reorderable(mut self, reorderable: bool) -> Self {
+				self.reorderable = reorderable;
+				self
+			}
+		}
+	})+};
+}
+
+sequences![Vec, VecDeque];
+
+/// `A` is the array being edited, so methods like `color` can be offered for only some sizes
+pub struct ArrayControlBuilder<'control, T, D, S, A> {
+	pub value: &'control mut A,
+	pub data: Option<&'control mut D>,
+	pub state: Option<&'control mut Vec<S>>,
+	pub label: Option<&'control imgui::ImStr>,
+	pub changed: Option<&'control mut bool>,
+	pub elements: std::marker::PhantomData<T>,
+}
+
+impl<'control, 'resource: 'control, T, A> ArrayControlBuilder<'control, T, <&'control mut T as InspectControl<'control, 'resource>>::SystemData, <&'control mut T as InspectControl<'control, 'resource>>::State, A>
+where
+	T: 'control,
+	A: AsMut<[T]>,
+	for<'c> &'c mut T: InspectControl<'c, 'resource, SystemData = <&'control mut T as InspectControl<'control, 'resource>>::SystemData, State = <&'control mut T as InspectControl<'control, 'resource>>::State>,
+{
+	fn draw(self) {
+		amethyst_imgui::with(|ui| {
+			let mut changed = false;
+			let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+			let value = self.value.as_mut();
+			let mut data = self.data;
+			let mut fallback = Vec::new();
+			let states = match self.state { Some(state) => state, None => &mut fallback };
+			states.resize_with(value.len(), Default::default);
+
+			ui.tree_node(label).label(&im_str!("{} [{}]", label, value.len())).build(|| {
+				for (i, (item, state)) in value.iter_mut().zip(states.iter_mut()).enumerate() {
+					let id = ui.push_id(i as i32);
+					let control = item.control().changed(&mut changed).label(&im_str!("[{}]", i)).state(state);
+					if let Some(data) = data.as_mut() {
+						control.data(&mut **data).build();
+					} else {
+						control.build();
+					}
+					id.pop(ui);
+				}
+			});
+
+			if let Some(x) = self.changed { *x = *x || changed };
+		});
+	}
+}
+
+macro_rules! arrays {
+	($($size:tt),+$(,)*) => {$(
+		impl<'control, 'resource: 'control, T> InspectControl<'control, 'resource> for &'control mut [T; $size]
+		where
+			T: 'control,
+			for<'c> &'c mut T: InspectControl<'c, 'resource, SystemData = <&'control mut T as InspectControl<'control, 'resource>>::SystemData, State = <&'control mut T as InspectControl<'control, 'resource>>::State>,
+		{
+			type SystemData = <&'control mut T as InspectControl<'control, 'resource>>::SystemData;
+			/// States of the elements
+			type State = Vec<<&'control mut T as InspectControl<'control, 'resource>>::State>;
+			type Builder = ArrayControlBuilder<'control, T, Self::SystemData, <&'control mut T as InspectControl<'control, 'resource>>::State, [T; $size]>;
+		}
+
+		impl<'control, 'resource: 'control, T> InspectControlBuilder<'control, 'resource, &'control mut [T; $size]> for ArrayControlBuilder<'control, T, <&'control mut T as InspectControl<'control, 'resource>>::SystemData, <&'control mut T as InspectControl<'control, 'resource>>::State, [T; $size]>
+		where
+			T: 'control,
+			for<'c> &'c mut T: InspectControl<'c, 'resource, SystemData = <&'control mut T as InspectControl<'control, 'resource>>::SystemData, State = <&'control mut T as InspectControl<'control, 'resource>>::State>,
+		{
+			fn new(value: &'control mut [T; $size]) -> Self {
+				Self { value, data: None, label: None, state: None, changed: None, elements: std::marker::PhantomData }
+			}
+			fn state(mut self, state: &'control mut Vec<<&'control mut T as InspectControl<'control, 'resource>>::State>) -> Self {
+				self.state = Some(state);
+				self
+			}
+			fn data(mut self, data: &'control mut <&'control mut T as InspectControl<'control, 'resource>>::SystemData) -> Self {
+				self.data = Some(data);
+				self
+			}
+			fn label(mut self, label: &'control imgui::ImStr) -> Self {
+				self.label = Some(label);
+				self
+			}
+			fn changed(mut self, changed: &'control mut bool) -> Self {
+				self.changed = Some(changed);
+				self
+			}
+			fn build(self) { self.draw() }
+		}
+	)+};
+}
+
+arrays![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32];
@@ -0,0 +1,153 @@
+use crate::{controls::collections::ArrayControlBuilder, prelude::*};
+use amethyst::renderer::palette::{LinSrgba, Srgb, Srgba};
+
+/// Anything that can be edited with a colour picker
+pub trait Color {
+	fn rgba(&self) -> [f32; 4];
+	fn set_rgba(&mut self, rgba: [f32; 4]);
+	fn has_alpha(&self) -> bool { true }
+}
+
+impl Color for Srgba {
+	fn rgba(&self) -> [f32; 4] {
+		let (r, g, b, a) = self.into_components();
+		[r, g, b, a]
+	}
+	fn set_rgba(&mut self, [r, g, b, a]: [f32; 4]) { *self = Srgba::from_components((r, g, b, a)); }
+}
+
+impl Color for LinSrgba {
+	fn rgba(&self) -> [f32; 4] {
+		let (r, g, b, a) = self.into_components();
+		[r, g, b, a]
+	}
+	fn set_rgba(&mut self, [r, g, b, a]: [f32; 4]) { *self = LinSrgba::from_components((r, g, b, a)); }
+}
+
+impl Color for Srgb {
+	fn rgba(&self) -> [f32; 4] {
+		let (r, g, b) = self.into_components();
+		[r, g, b, 1.]
+	}
+	fn set_rgba(&mut self, [r, g, b, _]: [f32; 4]) { *self = Srgb::from_components((r, g, b)); }
+	fn has_alpha(&self) -> bool { false }
+}
+
+impl Color for [f32; 3] {
+	fn rgba(&self) -> [f32; 4] { [self[0], self[1], self[2], 1.] }
+	fn set_rgba(&mut self, [r, g, b, _]: [f32; 4]) { *self = [r, g, b]; }
+	fn has_alpha(&self) -> bool { false }
+}
+
+impl Color for [f32; 4] {
+	fn rgba(&self) -> [f32; 4] { *self }
+	fn set_rgba(&mut self, rgba: [f32; 4]) { *self = rgba; }
+}
+
+pub struct ColorControlBuilder<'control, V: ?Sized> {
+	pub value: &'control mut V,
+	pub label: Option<&'control imgui::ImStr>,
+	pub null_to: [f32; 4],
+	pub default: Option<[f32; 4]>,
+	pub alpha: bool,
+	pub changed: Option<&'control mut bool>,
+}
+
+impl<'control, V: Color + ?Sized> ColorControlBuilder<'control, V> {
+	fn with_value(value: &'control mut V) -> Self {
+		Self { value, label: None, null_to: [1., 1., 1., 1.], default: None, alpha: true, changed: None }
+	}
+
+	fn draw(self) {
+		amethyst_imgui::with(|ui| {
+			let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+			let mut rgba = self.value.rgba();
+			let mut changed = if self.alpha && self.value.has_alpha() {
+				imgui::ColorEdit::new(label, &mut rgba).options(false).alpha_bar(true).preview(imgui::ColorPreview::HalfAlpha).build(ui)
+			} else {
+				let mut rgb = [rgba[0], rgba[1], rgba[2]];
+				let changed = imgui::ColorEdit::new(label, &mut rgb).options(false).build(ui);
+				rgba[.. 3].copy_from_slice(&rgb);
+				changed
+			};
+			let hovered = ui.is_item_hovered();
+			changed = crate::controls::context::context_menu(ui, label, hovered, &mut rgba, &self.null_to, self.default.as_ref()) || changed;
+			if changed {
+				self.value.set_rgba(rgba);
+			}
+			if let Some(x) = self.changed { *x = *x || changed };
+		});
+	}
+
+	pub fn null_to(mut self, null_to: [f32; 4]) -> Self {
+		self.null_to = null_to;
+		self
+	}
+	/// Hide the alpha channel even if the value has one
+	pub fn alpha(mut self, alpha: bool) -> Self {
+		self.alpha = alpha;
+		self
+	}
+}
+
+macro_rules! colors {
+	($($type:ident),+$(,)*) => {$(
+		impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut $type {
+			type SystemData = ();
+			type State = ();
+			type Builder = ColorControlBuilder<'control, $type>;
+		}
+
+		impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut $type> for ColorControlBuilder<'control, $type> {
+			fn new(value: &'control mut $type) -> Self { Self::with_value(value) }
+			fn label(mut self, label: &'control imgui::ImStr) -> Self {
+				self.label = Some(label);
+				self
+			}
+			fn changed(mut self, changed: &'control mut bool) -> Self {
+				self.changed = Some(changed);
+				self
+			}
+			fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+				self.default = default.downcast_ref::<$type>().map(Color::rgba);
+				self
+			}
+			fn build(self) { self.draw() }
+		}
+	)+};
+}
+
+colors![Srgba, Srgb, LinSrgba];
+
+// `[f32; 3]` and `[f32; 4]` fields marked with `#[inspect(color)]`, the array control turns into this one with `color`
+macro_rules! color_arrays {
+	($($size:tt),+$(,)*) => {$(
+		impl<'control> ColorControlBuilder<'control, [f32; $size]> {
+			pub fn label(mut self, label: &'control imgui::ImStr) -> Self {
+				self.label = Some(label);
+				self
+			}
+			pub fn changed(mut self, changed: &'control mut bool) -> Self {
+				self.changed = Some(changed);
+				self
+			}
+			pub fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+				self.default = default.downcast_ref::<[f32; $size]>().map(Color::rgba);
+				self
+			}
+			pub fn build(self) { self.draw() }
+		}
+
+		impl<'control, D, S> ArrayControlBuilder<'control, f32, D, S, [f32; $size]> {
+			/// Edit with a colour picker instead of separate drags
+			pub fn color(self) -> ColorControlBuilder<'control, [f32; $size]> {
+				let mut builder = ColorControlBuilder::with_value(self.value);
+				builder.label = self.label;
+				builder.changed = self.changed;
+				builder
+			}
+		}
+	)+};
+}
+
+color_arrays![3, 4];
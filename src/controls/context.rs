@@ -0,0 +1,118 @@
+use crate::prelude::*;
+
+/// Text form of a value for the copy/paste entries of a control's context menu
+pub trait ClipboardText: Sized {
+	fn to_clipboard(&self) -> String;
+	fn from_clipboard(text: &str) -> Option<Self>;
+}
+
+macro_rules! clipboard_parse {
+	($($type:ty),+$(,)*) => {$(
+		impl ClipboardText for $type {
+			fn to_clipboard(&self) -> String { self.to_string() }
+			fn from_clipboard(text: &str) -> Option<Self> { text.trim().parse().ok() }
+		}
+	)+};
+}
+
+clipboard_parse![u8, u16, u32, u64, usize, i8, i16, i32, i64, f32, f64, bool, char];
+
+impl ClipboardText for String {
+	fn to_clipboard(&self) -> String { self.clone() }
+	fn from_clipboard(text: &str) -> Option<Self> { Some(text.to_owned()) }
+}
+
+impl ClipboardText for imgui::ImString {
+	fn to_clipboard(&self) -> String { self.to_str().to_owned() }
+	fn from_clipboard(text: &str) -> Option<Self> { Some(imgui::ImString::new(text)) }
+}
+
+// in seconds, whatever unit the control is showing
+impl ClipboardText for std::time::Duration {
+	fn to_clipboard(&self) -> String { crate::DurationUnit::Secs.to_unit(*self).to_string() }
+	fn from_clipboard(text: &str) -> Option<Self> {
+		text.trim().trim_end_matches('s').trim().parse().ok().filter(|x: &f64| *x >= 0.).map(|x| crate::DurationUnit::Secs.from_unit(x))
+	}
+}
+
+// colours are edited as rgba
+impl ClipboardText for [f32; 4] {
+	fn to_clipboard(&self) -> String { to_list(self.iter()) }
+	fn from_clipboard(text: &str) -> Option<Self> {
+		let rgba = from_list::<f32>(text, 4).or_else(|| from_list::<f32>(text, 3).map(|mut x| { x.push(1.); x }))?;
+		Some([rgba[0], rgba[1], rgba[2], rgba[3]])
+	}
+}
+
+// written the way rust does, `start..end` and `start..=end`
+impl<T: ClipboardText> ClipboardText for std::ops::Range<T> {
+	fn to_clipboard(&self) -> String { format!("{}..{}", self.start.to_clipboard(), self.end.to_clipboard()) }
+	fn from_clipboard(text: &str) -> Option<Self> {
+		let i = text.find("..").filter(|&i| !text[i + 2 ..].starts_with('='))?;
+		Some(T::from_clipboard(&text[.. i])? .. T::from_clipboard(&text[i + 2 ..])?)
+	}
+}
+
+impl<T: ClipboardText> ClipboardText for std::ops::RangeInclusive<T> {
+	fn to_clipboard(&self) -> String { format!("{}..={}", self.start().to_clipboard(), self.end().to_clipboard()) }
+	fn from_clipboard(text: &str) -> Option<Self> {
+		let i = text.find("..=")?;
+		Some(T::from_clipboard(&text[.. i])? ..= T::from_clipboard(&text[i + 3 ..])?)
+	}
+}
+
+/// Comma separated components, used for vectors and colours
+pub(crate) fn to_list<T: ToString>(values: impl IntoIterator<Item = T>) -> String {
+	values.into_iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+pub(crate) fn from_list<T: std::str::FromStr>(text: &str, len: usize) -> Option<Vec<T>> {
+	let text = text.trim().trim_start_matches(|c| c == '[' || c == '(').trim_end_matches(|c| c == ']' || c == ')');
+	let values = text.split(',').map(|x| x.trim().parse().ok()).collect::<Option<Vec<T>>>()?;
+	if values.len() == len { Some(values) } else { None }
+}
+
+/// Right click menu shared by the built-in controls: reset, reset to default, copy and paste
+///
+/// `hovered` is whether any of the control's widgets is hovered, so multi-widget controls open it from anywhere.
+pub(crate) fn context_menu<T: ClipboardText + Clone>(
+	ui: &imgui::Ui,
+	label: &imgui::ImStr,
+	hovered: bool,
+	value: &mut T,
+	null_to: &T,
+	default: Option<&T>,
+) -> bool {
+	let mut changed = false;
+	let id = ui.push_id(label);
+
+	if hovered && ui.is_mouse_clicked(imgui::MouseButton::Right) {
+		ui.open_popup(im_str!("context"));
+	}
+	ui.popup(im_str!("context"), || {
+		if ui.menu_item(im_str!("reset")).build() {
+			*value = null_to.clone();
+			changed = true;
+		}
+		if ui.menu_item(im_str!("reset to default")).enabled(default.is_some()).build() {
+			if let Some(default) = default {
+				*value = default.clone();
+				changed = true;
+			}
+		}
+		ui.separator();
+		if ui.menu_item(im_str!("copy")).build() {
+			ui.set_clipboard_text(&im_str!("{}", value.to_clipboard()));
+		}
+		let pasted = ui.clipboard_text().and_then(|text| T::from_clipboard(text.to_str()));
+		if ui.menu_item(im_str!("paste")).enabled(pasted.is_some()).build() {
+			if let Some(pasted) = pasted {
+				*value = pasted;
+				changed = true;
+			}
+		}
+	});
+
+	id.pop(ui);
+	changed
+}
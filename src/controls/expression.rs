@@ -0,0 +1,376 @@
+use crate::prelude::*;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+// only one field is typed into at a time, whichever thread or system draws it
+static EDITING: Mutex<Option<Editing>> = Mutex::new(None);
+
+fn editing() -> MutexGuard<'static, Option<Editing>> {
+	EDITING.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+struct Editing {
+	key: imgui::sys::ImGuiID,
+	text: imgui::ImString,
+	error: Option<String>,
+	focus: bool,
+}
+
+/// Numbers that can be typed in as an expression
+pub(crate) trait Expression: Copy + std::fmt::Display + std::str::FromStr {
+	fn to_f64(self) -> f64;
+	/// Integers are rounded and saturate at their bounds
+	fn from_f64(value: f64) -> Self;
+	/// Like `from_f64`, but out of range values are an error instead of saturating, for what the user typed in
+	fn checked_from_f64(value: f64) -> Result<Self, String>;
+}
+
+macro_rules! expressions {
+	(@round int $value:ident) => { $value.round() };
+	(@round float $value:ident) => { $value };
+	(@checked int $type:ident $value:ident) => {
+		// through i128 so the bounds of 64-bit integers are exact, f64 can't hold them
+		<$type as std::convert::TryFrom<i128>>::try_from($value.round() as i128)
+			.map_err(|_| format!("expected an integer in {} ..= {}", std::$type::MIN, std::$type::MAX))
+	};
+	(@checked float $type:ident $value:ident) => {
+		Some($value as $type).filter(|x| x.is_finite()).ok_or_else(|| "result doesn't fit".to_owned())
+	};
+	($([$type:ident $kind:ident]),+$(,)*) => {$(
+		impl Expression for $type {
+			fn to_f64(self) -> f64 { self as f64 }
+			fn from_f64(value: f64) -> Self { expressions!(@round $kind value) as $type }
+			fn checked_from_f64(value: f64) -> Result<Self, String> { expressions!(@checked $kind $type value) }
+		}
+	)+};
+}
+
+expressions![
+	[u8 int],
+	[u16 int],
+	[u32 int],
+	[u64 int],
+	[usize int],
+	[i8 int],
+	[i16 int],
+	[i32 int],
+	[i64 int],
+	[f32 float],
+	[f64 float],
+];
+
+/// Which field is typed into: the value's address hashed into the id stack, so neither fields with the same label
+/// nor values that reuse an address in another control get mixed up
+fn key<T>(value: &T) -> imgui::sys::ImGuiID {
+	unsafe { imgui::sys::igGetIDPtr(value as *const T as *const std::os::raw::c_void) }
+}
+
+/// Start typing into the field when it's ctrl+clicked
+pub(crate) fn ctrl_click<T: Expression>(ui: &imgui::Ui, hovered: bool, value: &T) {
+	if hovered && ui.is_mouse_clicked(imgui::MouseButton::Left) && ui.io().key_ctrl {
+		*editing() = Some(Editing { key: key(value), text: im_str!("{}", value), error: None, focus: true });
+	}
+}
+
+/// Draws the text entry in place of the field's drag while it's being typed into
+///
+/// Must be called with the same id stack as `ctrl_click`. Returns `None` when the field isn't being typed into, so the
+/// caller draws its usual widget, otherwise whether the value changed.
+pub(crate) fn entry<T: Expression>(ui: &imgui::Ui, label: &imgui::ImStr, value: &mut T) -> Option<bool> {
+	let mut editing = editing();
+	let key = key(&*value);
+	let edit = match editing.as_mut() {
+		Some(edit) if edit.key == key => edit,
+		_ => return None,
+	};
+
+	if edit.focus {
+		ui.set_keyboard_focus_here(imgui::FocusedWidget::Next);
+	}
+	let entered = ui.input_text(label, &mut edit.text).enter_returns_true(true).auto_select_all(true).resize_buffer(true).build();
+	let active = ui.is_item_active();
+	if let Some(error) = &edit.error {
+		ui.same_line(0.);
+		ui.text_colored([1., 0.3, 0.3, 1.], &im_str!("{}", error));
+	}
+
+	let mut changed = false;
+	if entered {
+		// plain numbers are parsed directly so wide integers don't go through f64
+		let text = edit.text.to_str().trim();
+		match text.parse::<T>().or_else(|_| evaluate(text, value.to_f64()).and_then(T::checked_from_f64)) {
+			Ok(v) => {
+				*value = v;
+				changed = true;
+				*editing = None;
+			},
+			Err(error) => {
+				edit.error = Some(error);
+				edit.focus = true;
+			},
+		}
+	} else if edit.focus {
+		edit.focus = false;
+	} else if !active || ui.is_key_pressed(ui.key_index(imgui::Key::Escape)) {
+		*editing = None;
+	}
+	Some(changed)
+}
+
+/// Evaluates `text` as arithmetic, `+=`, `-=`, `*=` and `/=` apply to `current`, which is also available as `x`
+///
+/// Supports `+ - * / % ^`, parentheses, the constants `pi`, `tau` and `e` and the functions
+/// `sqrt abs sin cos tan floor ceil round deg rad`.
+pub(crate) fn evaluate(text: &str, current: f64) -> Result<f64, String> {
+	let text = text.trim();
+	let (op, text) = match text.get(.. 2) {
+		Some(op @ "+=") | Some(op @ "-=") | Some(op @ "*=") | Some(op @ "/=") => (Some(op), &text[2 ..]),
+		_ => (None, text),
+	};
+
+	let mut parser = Parser { tokens: tokenize(text)?, position: 0, current };
+	let value = parser.expression()?;
+	if let Some(token) = parser.tokens.get(parser.position) {
+		return Err(format!("unexpected {}", token));
+	}
+
+	let value = match op {
+		Some("+=") => current + value,
+		Some("-=") => current - value,
+		Some("*=") => current * value,
+		Some("/=") => current / value,
+		_ => value,
+	};
+	if value.is_finite() { Ok(value) } else { Err("result is not finite".into()) }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+	Number(f64),
+	Name(String),
+	Operator(char),
+}
+
+impl std::fmt::Display for Token {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Token::Number(x) => write!(f, "{}", x),
+			Token::Name(x) => write!(f, "{}", x),
+			Token::Operator(x) => write!(f, "{}", x),
+		}
+	}
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+	let mut tokens = Vec::new();
+	let mut chars = text.chars().peekable();
+	while let Some(&c) = chars.peek() {
+		if c.is_whitespace() {
+			chars.next();
+		} else if c.is_ascii_digit() || c == '.' {
+			let mut number = String::new();
+			while let Some(&c) = chars.peek() {
+				// exponents, e.g. 1e-3
+				let sign = (c == '-' || c == '+') && number.ends_with(|x| x == 'e' || x == 'E');
+				if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || sign {
+					number.push(c);
+					chars.next();
+				} else {
+					break;
+				}
+			}
+			tokens.push(Token::Number(number.parse().map_err(|_| format!("invalid number {}", number))?));
+		} else if c.is_alphabetic() || c == '_' {
+			let mut name = String::new();
+			while let Some(&c) = chars.peek() {
+				if c.is_alphanumeric() || c == '_' {
+					name.push(c);
+					chars.next();
+				} else {
+					break;
+				}
+			}
+			tokens.push(Token::Name(name.to_lowercase()));
+		} else if "+-*/%^()".contains(c) {
+			tokens.push(Token::Operator(c));
+			chars.next();
+		} else {
+			return Err(format!("unexpected {}", c));
+		}
+	}
+	Ok(tokens)
+}
+
+struct Parser {
+	tokens: Vec<Token>,
+	position: usize,
+	current: f64,
+}
+
+impl Parser {
+	fn next(&mut self) -> Option<Token> {
+		let token = self.tokens.get(self.position).cloned();
+		self.position += 1;
+		token
+	}
+
+	fn eat(&mut self, operator: char) -> bool {
+		if self.tokens.get(self.position) == Some(&Token::Operator(operator)) {
+			self.position += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn expression(&mut self) -> Result<f64, String> {
+		let mut value = self.term()?;
+		loop {
+			if self.eat('+') {
+				value += self.term()?;
+			} else if self.eat('-') {
+				value -= self.term()?;
+			} else {
+				return Ok(value);
+			}
+		}
+	}
+
+	fn term(&mut self) -> Result<f64, String> {
+		let mut value = self.unary()?;
+		loop {
+			if self.eat('*') {
+				value *= self.unary()?;
+			} else if self.eat('/') {
+				value /= self.unary()?;
+			} else if self.eat('%') {
+				value %= self.unary()?;
+			} else {
+				return Ok(value);
+			}
+		}
+	}
+
+	fn unary(&mut self) -> Result<f64, String> {
+		if self.eat('-') {
+			Ok(-self.unary()?)
+		} else if self.eat('+') {
+			self.unary()
+		} else {
+			let base = self.atom()?;
+			// right associative and binds tighter than a leading minus, so -2^2 is -4
+			if self.eat('^') { Ok(base.powf(self.unary()?)) } else { Ok(base) }
+		}
+	}
+
+	fn atom(&mut self) -> Result<f64, String> {
+		match self.next() {
+			Some(Token::Number(x)) => Ok(x),
+			Some(Token::Operator('(')) => {
+				let value = self.expression()?;
+				if self.eat(')') { Ok(value) } else { Err("missing )".into()) }
+			},
+			Some(Token::Name(name)) => match name.as_str() {
+				"pi" => Ok(std::f64::consts::PI),
+				"tau" => Ok(2. * std::f64::consts::PI),
+				"e" => Ok(std::f64::consts::E),
+				"x" => Ok(self.current),
+				_ => {
+					let function: fn(f64) -> f64 = match name.as_str() {
+						"sqrt" => f64::sqrt,
+						"abs" => f64::abs,
+						"sin" => f64::sin,
+						"cos" => f64::cos,
+						"tan" => f64::tan,
+						"floor" => f64::floor,
+						"ceil" => f64::ceil,
+						"round" => f64::round,
+						"deg" => f64::to_degrees,
+						"rad" => f64::to_radians,
+						_ => return Err(format!("unknown name {}", name)),
+					};
+					if !self.eat('(') {
+						return Err(format!("expected ( after {}", name));
+					}
+					let value = self.expression()?;
+					if self.eat(')') { Ok(function(value)) } else { Err("missing )".into()) }
+				},
+			},
+			Some(token) => Err(format!("unexpected {}", token)),
+			None => Err("unexpected end".into()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{evaluate, Expression};
+
+	#[test]
+	fn precedence() {
+		assert_eq!(evaluate("1 + 2 * 3", 0.), Ok(7.));
+		assert_eq!(evaluate("(1 + 2) * 3", 0.), Ok(9.));
+		assert_eq!(evaluate("10 - 4 - 3", 0.), Ok(3.));
+		assert_eq!(evaluate("7 % 4 * 2", 0.), Ok(6.));
+	}
+
+	#[test]
+	fn power() {
+		assert_eq!(evaluate("2 ^ 3", 0.), Ok(8.));
+		assert_eq!(evaluate("2 ^ 3 ^ 2", 0.), Ok(512.));
+		assert_eq!(evaluate("2 * 3 ^ 2", 0.), Ok(18.));
+	}
+
+	#[test]
+	fn unary_minus() {
+		assert_eq!(evaluate("-3", 0.), Ok(-3.));
+		assert_eq!(evaluate("--3", 0.), Ok(3.));
+		assert_eq!(evaluate("-2 ^ 2", 0.), Ok(-4.));
+		assert_eq!(evaluate("2 ^ -1", 0.), Ok(0.5));
+		assert_eq!(evaluate("4 * -x", 2.), Ok(-8.));
+	}
+
+	#[test]
+	fn functions() {
+		assert_eq!(evaluate("sqrt(16) + abs(-2)", 0.), Ok(6.));
+		assert_eq!(evaluate("floor(2.7) + ceil(2.2) + round(2.5)", 0.), Ok(8.));
+		assert!((evaluate("deg(pi)", 0.).unwrap() - 180.).abs() < 1e-9);
+		assert!((evaluate("sin(rad(90))", 0.).unwrap() - 1.).abs() < 1e-12);
+		assert!(evaluate("sqrt 4", 0.).is_err());
+		assert!(evaluate("foo(1)", 0.).is_err());
+	}
+
+	#[test]
+	fn assignments() {
+		assert_eq!(evaluate("+= 2", 5.), Ok(7.));
+		assert_eq!(evaluate("-= 2 * 2", 5.), Ok(1.));
+		assert_eq!(evaluate("*= 3", 5.), Ok(15.));
+		assert_eq!(evaluate("/= 2", 5.), Ok(2.5));
+		assert_eq!(evaluate("x * 2", 5.), Ok(10.));
+	}
+
+	#[test]
+	fn division_by_zero() {
+		assert!(evaluate("1 / 0", 0.).is_err());
+		assert!(evaluate("/= 0", 5.).is_err());
+		assert!(evaluate("0 / 0", 0.).is_err());
+	}
+
+	#[test]
+	fn malformed() {
+		assert!(evaluate("1 +", 0.).is_err());
+		assert!(evaluate("(1 + 2", 0.).is_err());
+		assert!(evaluate("1 2", 0.).is_err());
+		assert!(evaluate("1 $ 2", 0.).is_err());
+	}
+
+	#[test]
+	fn out_of_range() {
+		assert_eq!(u8::checked_from_f64(255.4), Ok(255));
+		assert!(u8::checked_from_f64(256.).is_err());
+		assert!(u64::checked_from_f64(-1.).is_err());
+		assert!(u64::checked_from_f64(2f64.powi(64)).is_err());
+		assert_eq!(i64::checked_from_f64(-2f64.powi(63)), Ok(std::i64::MIN));
+		assert!(f32::checked_from_f64(1e300).is_err());
+		assert_eq!(u8::from_f64(300.), 255);
+	}
+}
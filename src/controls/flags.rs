@@ -0,0 +1,100 @@
+use crate::prelude::*;
+
+/// Bitflags-style sets that can be shown as a list of checkboxes, implement with `inspect_flags!`
+pub trait Flags: Copy + 'static {
+	fn flags() -> &'static [(&'static str, Self)];
+	fn contains(&self, flag: Self) -> bool;
+	fn set(&mut self, flag: Self, value: bool);
+}
+
+#[derive(Clone)]
+struct FlagSet<F>(F);
+
+// names of the set flags, `a | b`
+impl<F: Flags> crate::controls::context::ClipboardText for FlagSet<F> {
+	fn to_clipboard(&self) -> String {
+		F::flags().iter().filter(|(_, flag)| self.0.contains(*flag)).map(|(name, _)| *name).collect::<Vec<_>>().join(" | ")
+	}
+	fn from_clipboard(text: &str) -> Option<Self> {
+		let mut value = *F::flags().first().map(|(_, flag)| flag)?;
+		for &(_, flag) in F::flags() {
+			value.set(flag, false);
+		}
+		for name in text.split('|').map(str::trim).filter(|x| !x.is_empty()) {
+			let flag = F::flags().iter().find(|(x, _)| *x == name)?.1;
+			value.set(flag, true);
+		}
+		Some(FlagSet(value))
+	}
+}
+
+pub struct FlagsControlBuilder<'control, F> {
+	pub value: &'control mut F,
+	pub label: Option<&'control imgui::ImStr>,
+	pub null_to: Option<F>,
+	pub default: Option<F>,
+	pub changed: Option<&'control mut bool>,
+}
+
+impl<'control, 'resource: 'control, F: Flags> InspectControlBuilder<'control, 'resource, &'control mut F> for FlagsControlBuilder<'control, F>
+where
+	&'control mut F: InspectControl<'control, 'resource>,
+{
+	fn new(value: &'control mut F) -> Self {
+		Self { value, label: None, null_to: None, default: None, changed: None }
+	}
+	fn label(mut self, label: &'control imgui::ImStr) -> Self {
+		self.label = Some(label);
+		self
+	}
+	fn changed(mut self, changed: &'control mut bool) -> Self {
+		self.changed = Some(changed);
+		self
+	}
+	fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+		self.default = default.downcast_ref::<F>().cloned();
+		self
+	}
+	fn build(self) {
+		amethyst_imgui::with(|ui| {
+			let mut changed = false;
+			let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+			let value = &mut *self.value;
+			let null_to = self.null_to;
+
+			// the header is only the last item while the node is closed
+			let mut hovered = None;
+			ui.tree_node(label).build(|| {
+				hovered = Some(ui.is_item_hovered());
+				for &(name, flag) in F::flags() {
+					let mut set = value.contains(flag);
+					if ui.checkbox(&im_str!("{}", name), &mut set) {
+						value.set(flag, set);
+						changed = true;
+					}
+				}
+			});
+			let hovered = hovered.unwrap_or_else(|| ui.is_item_hovered());
+
+			// clearing every flag stands in for an empty set
+			let mut empty = *value;
+			for &(_, flag) in F::flags() {
+				empty.set(flag, false);
+			}
+			let mut set = FlagSet(*value);
+			if crate::controls::context::context_menu(ui, label, hovered, &mut set, &FlagSet(null_to.unwrap_or(empty)), self.default.map(FlagSet).as_ref()) {
+				*value = set.0;
+				changed = true;
+			}
+
+			if let Some(x) = self.changed { *x = *x || changed };
+		});
+	}
+}
+
+impl<'control, F> FlagsControlBuilder<'control, F> {
+	pub fn null_to(mut self, null_to: F) -> Self {
+		self.null_to = Some(null_to);
+		self
+	}
+}
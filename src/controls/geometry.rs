@@ -0,0 +1,341 @@
+use crate::prelude::*;
+use amethyst::core::math::{Isometry2, Isometry3, Matrix3, Matrix4, Quaternion, UnitComplex, UnitQuaternion};
+
+macro_rules! geometry {
+	($($type:ident),+$(,)*) => {$(paste::item!{
+		mod [<geometry_$type>] {
+			use super::*;
+
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut UnitQuaternion<$type> {
+				type SystemData = ();
+				/// Whether it's edited as raw components
+				type State = bool;
+				type Builder = UnitQuaternionControlBuilder<'control>;
+			}
+
+			/// Edits as euler angles in degrees, or as raw `i j k w` components when "raw" is ticked
+			pub struct UnitQuaternionControlBuilder<'control> {
+				pub value: &'control mut UnitQuaternion<$type>,
+				pub label: Option<&'control imgui::ImStr>,
+				pub speed: f32,
+				pub state: Option<&'control mut bool>,
+				pub changed: Option<&'control mut bool>,
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut UnitQuaternion<$type>> for UnitQuaternionControlBuilder<'control> {
+				fn new(value: &'control mut UnitQuaternion<$type>) -> Self {
+					Self { value, label: None, speed: 0.25, state: None, changed: None }
+				}
+				fn state(mut self, state: &'control mut bool) -> Self {
+					self.state = Some(state);
+					self
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) {
+					amethyst_imgui::with(|ui| {
+						let mut changed = false;
+						let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+						let id = ui.push_id(label);
+						let mut fallback = false;
+						let raw = match self.state { Some(state) => state, None => &mut fallback };
+
+						if *raw {
+							let mut v = self.value.quaternion().coords;
+							v.control().null_to(0.).speed(self.speed * 0.01).label(label).changed(&mut changed).build();
+							if changed {
+								if let Some(rotation) = UnitQuaternion::try_new(Quaternion::from(v), std::$type::EPSILON) {
+									*self.value = rotation;
+								}
+							}
+						} else {
+							let (x, y, z) = self.value.euler_angles();
+							let mut v = Vector3::new(x.to_degrees(), y.to_degrees(), z.to_degrees());
+							v.control().null_to(0.).speed(self.speed).label(label).changed(&mut changed).build();
+							if changed {
+								*self.value = UnitQuaternion::from_euler_angles(v[0].to_radians(), v[1].to_radians(), v[2].to_radians());
+							}
+						}
+
+						ui.same_line(0.);
+						ui.checkbox(im_str!("raw"), raw);
+
+						id.pop(ui);
+						if let Some(x) = self.changed { *x = *x || changed };
+					});
+				}
+			}
+
+			impl<'control> UnitQuaternionControlBuilder<'control> {
+				pub fn speed(mut self, speed: f32) -> Self {
+					self.speed = speed;
+					self
+				}
+			}
+
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut UnitComplex<$type> {
+				type SystemData = ();
+				type State = ();
+				type Builder = UnitComplexControlBuilder<'control>;
+			}
+
+			/// Edits the angle in degrees
+			pub struct UnitComplexControlBuilder<'control> {
+				pub value: &'control mut UnitComplex<$type>,
+				pub label: Option<&'control imgui::ImStr>,
+				pub speed: f32,
+				pub changed: Option<&'control mut bool>,
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut UnitComplex<$type>> for UnitComplexControlBuilder<'control> {
+				fn new(value: &'control mut UnitComplex<$type>) -> Self {
+					Self { value, label: None, speed: 0.25, changed: None }
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) {
+					let mut changed = false;
+					let mut angle = self.value.angle().to_degrees();
+					angle.control().null_to(0.).speed(self.speed).label(&crate::controls::label_or_id(self.label, &*self.value)).changed(&mut changed).build();
+					if changed {
+						*self.value = UnitComplex::new(angle.to_radians());
+					}
+					if let Some(x) = self.changed { *x = *x || changed };
+				}
+			}
+
+			impl<'control> UnitComplexControlBuilder<'control> {
+				pub fn speed(mut self, speed: f32) -> Self {
+					self.speed = speed;
+					self
+				}
+			}
+
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut Isometry2<$type> {
+				type SystemData = ();
+				type State = ();
+				type Builder = Isometry2ControlBuilder<'control>;
+			}
+
+			pub struct Isometry2ControlBuilder<'control> {
+				pub value: &'control mut Isometry2<$type>,
+				pub label: Option<&'control imgui::ImStr>,
+				pub speed: f32,
+				pub changed: Option<&'control mut bool>,
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut Isometry2<$type>> for Isometry2ControlBuilder<'control> {
+				fn new(value: &'control mut Isometry2<$type>) -> Self {
+					Self { value, label: None, speed: 1., changed: None }
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) {
+					amethyst_imgui::with(|ui| {
+						let mut changed = false;
+						let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+						let value = &mut *self.value;
+						let speed = self.speed;
+						ui.tree_node(label).build(|| {
+							value.translation.control().null_to(0.).speed(speed).label(im_str!("translation")).changed(&mut changed).build();
+							value.rotation.control().label(im_str!("rotation")).changed(&mut changed).build();
+						});
+						if let Some(x) = self.changed { *x = *x || changed };
+					});
+				}
+			}
+
+			impl<'control> Isometry2ControlBuilder<'control> {
+				/// Speed of the translation drags
+				pub fn speed(mut self, speed: f32) -> Self {
+					self.speed = speed;
+					self
+				}
+			}
+
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut Isometry3<$type> {
+				type SystemData = ();
+				/// State of the rotation
+				type State = bool;
+				type Builder = Isometry3ControlBuilder<'control>;
+			}
+
+			pub struct Isometry3ControlBuilder<'control> {
+				pub value: &'control mut Isometry3<$type>,
+				pub label: Option<&'control imgui::ImStr>,
+				pub speed: f32,
+				pub state: Option<&'control mut bool>,
+				pub changed: Option<&'control mut bool>,
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut Isometry3<$type>> for Isometry3ControlBuilder<'control> {
+				fn new(value: &'control mut Isometry3<$type>) -> Self {
+					Self { value, label: None, speed: 1., state: None, changed: None }
+				}
+				fn state(mut self, state: &'control mut bool) -> Self {
+					self.state = Some(state);
+					self
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) {
+					amethyst_imgui::with(|ui| {
+						let mut changed = false;
+						let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+						let value = &mut *self.value;
+						let speed = self.speed;
+						let state = self.state;
+						ui.tree_node(label).build(|| {
+							value.translation.control().null_to(0.).speed(speed).label(im_str!("translation")).changed(&mut changed).build();
+							let rotation = value.rotation.control().label(im_str!("rotation")).changed(&mut changed);
+							if let Some(state) = state { rotation.state(state).build() } else { rotation.build() }
+						});
+						if let Some(x) = self.changed { *x = *x || changed };
+					});
+				}
+			}
+
+			impl<'control> Isometry3ControlBuilder<'control> {
+				/// Speed of the translation drags
+				pub fn speed(mut self, speed: f32) -> Self {
+					self.speed = speed;
+					self
+				}
+			}
+		}
+	})+};
+}
+
+geometry![f32, f64];
+
+macro_rules! matrices {
+	($([$size:tt $type:ident]),+$(,)*) => {$(paste::item!{
+		mod [<matrix$type$size>] {
+			use super::*;
+
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut [<Matrix$size>]<$type> {
+				type SystemData = ();
+				type State = ();
+				type Builder = Builder<'control>;
+			}
+
+			/// Grid editor, one row of drags per matrix row
+			pub struct Builder<'control> {
+				pub value: &'control mut [<Matrix$size>]<$type>,
+				pub label: Option<&'control imgui::ImStr>,
+				pub speed: f32,
+				pub null_to: [<Matrix$size>]<$type>,
+				pub default: Option<[<Matrix$size>]<$type>>,
+				pub changed: Option<&'control mut bool>,
+			}
+
+			// row by row, the way the grid shows it
+			impl crate::controls::context::ClipboardText for [<Matrix$size>]<$type> {
+				fn to_clipboard(&self) -> String { crate::controls::context::to_list(self.transpose().iter()) }
+				fn from_clipboard(text: &str) -> Option<Self> {
+					crate::controls::context::from_list(text, $size * $size).map(|x| Self::from_row_slice(&x))
+				}
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut [<Matrix$size>]<$type>> for Builder<'control> {
+				fn new(value: &'control mut [<Matrix$size>]<$type>) -> Self {
+					Self { value, label: None, speed: 0.01, null_to: [<Matrix$size>]::identity(), default: None, changed: None }
+				}
+				fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+					self.default = default.downcast_ref::<[<Matrix$size>]<$type>>().cloned();
+					self
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) {
+					amethyst_imgui::with(|ui| {
+						let mut changed = false;
+						let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+						let value = &mut *self.value;
+						let speed = self.speed;
+
+						// the cells have no menus of their own, the whole matrix's opens from the header
+						let mut header_hovered = None;
+						ui.tree_node(label).build(|| {
+							header_hovered = Some(ui.is_item_hovered());
+							let spacing = ui.clone_style().item_inner_spacing[0];
+							let width = ((ui.window_size()[0] - spacing * (($size - 1) as f32 * 1.5)) * 0.65) / $size as f32;
+
+							for row in 0 .. $size {
+								let row_id = ui.push_id(row as i32);
+								for column in 0 .. $size {
+									let column_id = ui.push_id(column as i32);
+									let token = ui.push_item_width(width);
+									let mut v = value[(row, column)] as f32;
+									// only the dragged cell goes through f32, the others keep their full precision
+									if ui.drag_float(im_str!(""), &mut v).speed(speed).build() {
+										value[(row, column)] = v as $type;
+										changed = true;
+									}
+									if column < $size - 1 {
+										ui.same_line_with_spacing(0., spacing);
+									}
+									drop(token);
+									column_id.pop(ui);
+								}
+								row_id.pop(ui);
+							}
+						});
+						let hovered = header_hovered.unwrap_or_else(|| ui.is_item_hovered());
+
+						changed = crate::controls::context::context_menu(ui, label, hovered, value, &self.null_to, self.default.as_ref()) || changed;
+						if let Some(x) = self.changed { *x = *x || changed };
+					});
+				}
+			}
+
+			impl<'control> Builder<'control> {
+				pub fn speed(mut self, speed: f32) -> Self {
+					self.speed = speed;
+					self
+				}
+				pub fn null_to(mut self, null_to: [<Matrix$size>]<$type>) -> Self {
+					self.null_to = null_to;
+					self
+				}
+			}
+		}
+	})+};
+}
+
+matrices![
+	[3 f32],
+	[4 f32],
+	[3 f64],
+	[4 f64],
+];
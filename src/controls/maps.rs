@@ -0,0 +1,120 @@
+use crate::prelude::*;
+use std::{
+	collections::{BTreeMap, HashMap},
+	fmt::Display,
+	hash::Hash,
+	str::FromStr,
+};
+
+macro_rules! maps {
+	($([$container:ident $($bound:tt)+]),+$(,)*) => {$(paste::item!{
+		impl<'control, 'resource: 'control, K, V> InspectControl<'control, 'resource> for &'control mut $container<K, V>
+		where
+			K: Clone + Display + FromStr + $($bound)+ + 'control,
+			V: Default + 'control,
+			for<'c> &'c mut V: InspectControl<'c, 'resource, SystemData = <&'control mut V as InspectControl<'control, 'resource>>::SystemData, State = <&'control mut V as InspectControl<'control, 'resource>>::State>,
+		{
+			type SystemData = <&'control mut V as InspectControl<'control, 'resource>>::SystemData;
+			/// The key being typed in and the states of the values by their displayed key
+			type State = (imgui::ImString, HashMap<String, <&'control mut V as InspectControl<'control, 'resource>>::State>);
+			type Builder = [<$container ControlBuilder>]<'control, K, V, Self::SystemData, <&'control mut V as InspectControl<'control, 'resource>>::State>;
+		}
+
+		pub struct [<$container ControlBuilder>]<'control, K, V, D, S> {
+			pub value: &'control mut $container<K, V>,
+			pub data: Option<&'control mut D>,
+			pub state: Option<&'control mut (imgui::ImString, HashMap<String, S>)>,
+			pub label: Option<&'control imgui::ImStr>,
+			pub changed: Option<&'control mut bool>,
+		}
+
+		impl<'control, 'resource: 'control, K, V> InspectControlBuilder<'control, 'resource, &'control mut $container<K, V>> for [<$container ControlBuilder>]<'control, K, V, <&'control mut V as InspectControl<'control, 'resource>>::SystemData, <&'control mut V as InspectControl<'control, 'resource>>::State>
+		where
+			K: Clone + Display + FromStr + $($bound)+ + 'control,
+			V: Default + 'control,
+			for<'c> &'c mut V: InspectControl<'c, 'resource, SystemData = <&'control mut V as InspectControl<'control, 'resource>>::SystemData, State = <&'control mut V as InspectControl<'control, 'resource>>::State>,
+		{
+			fn new(value: &'control mut $container<K, V>) -> Self {
+				Self { value, data: None, label: None, state: None, changed: None }
+			}
+			fn state(mut self, state: &'control mut (imgui::ImString, HashMap<String, <&'control mut V as InspectControl<'control, 'resource>>::State>)) -> Self {
+				self.state = Some(state);
+				self
+			}
+			fn data(mut self, data: &'control mut <&'control mut V as InspectControl<'control, 'resource>>::SystemData) -> Self {
+				self.data = Some(data);
+				self
+			}
+			fn label(mut self, label: &'control imgui::ImStr) -> Self {
+				self.label = Some(label);
+				self
+			}
+			fn changed(mut self, changed: &'control mut bool) -> Self {
+				self.changed = Some(changed);
+				self
+			}
+			fn build(self) {
+				amethyst_imgui::with(|ui| {
+					let mut changed = false;
+					let mut remove = None;
+					let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+					let value = &mut *self.value;
+					let mut data = self.data;
+					let mut fallback = (imgui::ImString::with_capacity(32), HashMap::new());
+					let (new_key, states) = match self.state { Some(state) => state, None => &mut fallback };
+
+					ui.tree_node(label).label(&im_str!("{} [{}]", label, value.len())).build(|| {
+						// sort by the displayed key so HashMap rows don't jump around between frames
+						let mut keys = value.keys().map(|key| (key.to_string(), key.clone())).collect::<Vec<_>>();
+						keys.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+						for (i, (display, key)) in keys.into_iter().enumerate() {
+							let id = ui.push_id(i as i32);
+							if ui.small_button(im_str!("del")) {
+								remove = Some(key.clone());
+							}
+							ui.same_line(0.);
+
+							let state = states.entry(display.clone()).or_insert_with(Default::default);
+							let control = value.get_mut(&key).unwrap_or_else(f!()).control().changed(&mut changed).label(&im_str!("{}", display)).state(state);
+							if let Some(data) = data.as_mut() {
+								control.data(&mut **data).build();
+							} else {
+								control.build();
+							}
+							id.pop(ui);
+						}
+
+						ui.input_text(im_str!("##new_key"), new_key).resize_buffer(true).build();
+						ui.same_line(0.);
+						let add = ui.small_button(im_str!("add"));
+
+						match K::from_str(new_key.to_str()) {
+							Ok(_) if new_key.is_empty() => {},
+							Ok(ref key) if value.contains_key(key) => ui.text_colored([1., 0.3, 0.3, 1.], im_str!("key already exists")),
+							Ok(key) => if add {
+								value.insert(key, V::default());
+								new_key.clear();
+								changed = true;
+							},
+							Err(_) => ui.text_colored([1., 0.3, 0.3, 1.], im_str!("invalid key")),
+						}
+					});
+
+					if let Some(key) = remove {
+						states.remove(&key.to_string());
+						value.remove(&key);
+						changed = true;
+					}
+
+					if let Some(x) = self.changed { *x = *x || changed };
+				});
+			}
+		}
+	})+};
+}
+
+maps![
+	[HashMap Eq + Hash],
+	[BTreeMap Ord],
+];
@@ -1,21 +1,83 @@
 use crate::prelude::*;
+use std::time::Duration;
 
-impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut std::time::Duration {
+/// Unit a `Duration` control edits in, selectable from a dropdown next to the field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationUnit {
+	Nanos,
+	Micros,
+	Millis,
+	Secs,
+	Mins,
+}
+
+impl DurationUnit {
+	const ALL: [DurationUnit; 5] = [DurationUnit::Nanos, DurationUnit::Micros, DurationUnit::Millis, DurationUnit::Secs, DurationUnit::Mins];
+
+	fn nanos(self) -> f64 {
+		match self {
+			DurationUnit::Nanos => 1.,
+			DurationUnit::Micros => 1e3,
+			DurationUnit::Millis => 1e6,
+			DurationUnit::Secs => 1e9,
+			DurationUnit::Mins => 60e9,
+		}
+	}
+
+	fn suffix(self) -> &'static imgui::ImStr {
+		match self {
+			DurationUnit::Nanos => im_str!("ns"),
+			DurationUnit::Micros => im_str!("µs"),
+			DurationUnit::Millis => im_str!("ms"),
+			DurationUnit::Secs => im_str!("s"),
+			DurationUnit::Mins => im_str!("min"),
+		}
+	}
+
+	pub fn to_unit(self, duration: Duration) -> f64 {
+		(duration.as_secs() as f64 * 1e9 + f64::from(duration.subsec_nanos())) / self.nanos()
+	}
+
+	pub fn from_unit(self, value: f64) -> Duration {
+		let nanos = (value * self.nanos()).max(0.);
+		Duration::new((nanos / 1e9) as u64, (nanos % 1e9) as u32)
+	}
+}
+
+impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut Duration {
 	type SystemData = ();
+	/// Unit picked from the dropdown, if it was changed from the builder's
+	type State = Option<DurationUnit>;
 	type Builder = DurationControlBuilder<'control>;
 }
 
 pub struct DurationControlBuilder<'control> {
-	pub value: &'control mut std::time::Duration,
+	pub value: &'control mut Duration,
 	pub label: Option<&'control imgui::ImStr>,
 	pub speed: f32,
-	pub null_to: std::time::Duration,
+	pub unit: DurationUnit,
+	pub min: Duration,
+	pub max: Option<Duration>,
+	pub null_to: Duration,
+	pub default: Option<Duration>,
+	pub state: Option<&'control mut Option<DurationUnit>>,
 	pub changed: Option<&'control mut bool>,
 }
 
-impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut std::time::Duration> for DurationControlBuilder<'control> {
-	fn new(value: &'control mut std::time::Duration) -> Self {
-		Self { value, label: None, speed: 1., null_to: <std::time::Duration as Default>::default(), changed: None }
+impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut Duration> for DurationControlBuilder<'control> {
+	fn new(value: &'control mut Duration) -> Self {
+		Self {
+			value,
+			label: None,
+			speed: 1.,
+			unit: DurationUnit::Millis,
+			min: Duration::default(),
+			max: None,
+			null_to: <Duration as Default>::default(),
+			default: None,
+			state: None,
+			changed: None,
+		}
 	}
 	fn label(mut self, label: &'control imgui::ImStr) -> Self {
 		self.label = Some(label);
@@ -25,15 +87,53 @@ impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &
 		self.changed = Some(changed);
 		self
 	}
+	fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+		self.default = default.downcast_ref::<Duration>().cloned();
+		self
+	}
+	fn state(mut self, state: &'control mut Option<DurationUnit>) -> Self {
+		self.state = Some(state);
+		self
+	}
 	fn build(self) {
 		amethyst_imgui::with(|ui| {
-			let mut v = self.value.as_millis() as i32;
-			let mut changed = ui.drag_int(self.label.unwrap(), &mut v).speed(self.speed).min(0).build();
-			*self.value = std::time::Duration::from_millis(v as u64);
-			if ui.is_item_hovered() && ui.is_mouse_down(imgui::MouseButton::Right) {
-				changed = true;
-				*self.value = self.null_to;
+			let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+			let id = ui.push_id(label);
+			let mut fallback = None;
+			let state = match self.state { Some(state) => state, None => &mut fallback };
+			let mut unit = state.unwrap_or(self.unit);
+			let spacing = ui.clone_style().item_inner_spacing[0];
+			let unit_width = ui.calc_text_size(im_str!("min"), false, 0.)[0] + ui.clone_style().frame_padding[0] * 2. + ui.frame_height();
+
+			let token = ui.push_item_width(ui.window_size()[0] * 0.65 - unit_width - spacing);
+			// drags a delta and adds it to the exact nanoseconds, so long durations don't lose precision through f32
+			let mut delta = 0f32;
+			let mut changed = ui.drag_float(im_str!("##value"), &mut delta).speed(self.speed).display_format(&im_str!("{:.3}", unit.to_unit(*self.value))).build();
+			changed = changed && delta != 0.;
+			if changed {
+				let nanos = self.value.as_nanos() as i128 + (f64::from(delta) * unit.nanos()).round() as i128;
+				let max = self.max.map_or(std::i128::MAX, |max| max.as_nanos() as i128);
+				let nanos = nanos.max(self.min.as_nanos() as i128).min(max) as u128;
+				*self.value = Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32);
 			}
+			let hovered = ui.is_item_hovered();
+			drop(token);
+
+			ui.same_line_with_spacing(0., spacing);
+			let token = ui.push_item_width(unit_width);
+			let mut current = DurationUnit::ALL.iter().position(|&x| x == unit).unwrap_or(0);
+			let items = DurationUnit::ALL.iter().map(|x| x.suffix()).collect::<Vec<_>>();
+			if imgui::ComboBox::new(im_str!("##unit")).build_simple_string(ui, &mut current, items.as_slice()) {
+				unit = DurationUnit::ALL[current];
+				*state = Some(unit);
+			}
+			drop(token);
+
+			ui.same_line_with_spacing(0., spacing);
+			ui.text(crate::controls::visible_label(label));
+			id.pop(ui);
+
+			changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &self.null_to, self.default.as_ref()) || changed;
 			if let Some(x) = self.changed { *x = *x || changed };
 		});
 	}
@@ -44,8 +144,127 @@ impl<'control> DurationControlBuilder<'control> {
 		self.speed = speed;
 		self
 	}
+	/// Unit the field starts out edited in, the user can switch it from the dropdown
+	pub fn unit(mut self, unit: DurationUnit) -> Self {
+		self.unit = unit;
+		self
+	}
+	pub fn min(mut self, min: Duration) -> Self {
+		self.min = min;
+		self
+	}
+	pub fn max(mut self, max: Duration) -> Self {
+		self.max = Some(max);
+		self
+	}
 	pub fn null_to(mut self, null_to: u64) -> Self {
-		self.null_to = std::time::Duration::from_millis(null_to);
+		self.null_to = Duration::from_millis(null_to);
+		self
+	}
+}
+
+impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut bool {
+	type SystemData = ();
+	type State = ();
+	type Builder = BoolControlBuilder<'control>;
+}
+
+pub struct BoolControlBuilder<'control> {
+	pub value: &'control mut bool,
+	pub label: Option<&'control imgui::ImStr>,
+	pub null_to: bool,
+	pub default: Option<bool>,
+	pub changed: Option<&'control mut bool>,
+}
+
+impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut bool> for BoolControlBuilder<'control> {
+	fn new(value: &'control mut bool) -> Self {
+		Self { value, label: None, null_to: false, default: None, changed: None }
+	}
+	fn label(mut self, label: &'control imgui::ImStr) -> Self {
+		self.label = Some(label);
+		self
+	}
+	fn changed(mut self, changed: &'control mut bool) -> Self {
+		self.changed = Some(changed);
+		self
+	}
+	fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+		self.default = default.downcast_ref::<bool>().cloned();
+		self
+	}
+	fn build(self) {
+		amethyst_imgui::with(|ui| {
+			let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+			let mut changed = ui.checkbox(label, self.value);
+			let hovered = ui.is_item_hovered();
+			changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &self.null_to, self.default.as_ref()) || changed;
+			if let Some(x) = self.changed { *x = *x || changed };
+		});
+	}
+}
+
+impl<'control> BoolControlBuilder<'control> {
+	pub fn null_to(mut self, null_to: bool) -> Self {
+		self.null_to = null_to;
+		self
+	}
+}
+
+impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut char {
+	type SystemData = ();
+	type State = ();
+	type Builder = CharControlBuilder<'control>;
+}
+
+pub struct CharControlBuilder<'control> {
+	pub value: &'control mut char,
+	pub label: Option<&'control imgui::ImStr>,
+	pub null_to: char,
+	pub default: Option<char>,
+	pub changed: Option<&'control mut bool>,
+}
+
+impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut char> for CharControlBuilder<'control> {
+	fn new(value: &'control mut char) -> Self {
+		Self { value, label: None, null_to: <char as Default>::default(), default: None, changed: None }
+	}
+	fn label(mut self, label: &'control imgui::ImStr) -> Self {
+		self.label = Some(label);
+		self
+	}
+	fn changed(mut self, changed: &'control mut bool) -> Self {
+		self.changed = Some(changed);
+		self
+	}
+	fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+		self.default = default.downcast_ref::<char>().cloned();
+		self
+	}
+	fn build(self) {
+		amethyst_imgui::with(|ui| {
+			let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+			let mut buf = imgui::ImString::with_capacity(4);
+			if *self.value != '\0' {
+				buf.push(*self.value);
+			}
+			let mut changed = ui.input_text(label, &mut buf).resize_buffer(true).auto_select_all(true).build();
+			if changed {
+				// whatever was typed next to the old character wins, wherever the cursor was
+				let old = *self.value;
+				let mut chars = buf.to_str().chars();
+				*self.value = chars.clone().find(|&x| x != old).or_else(|| chars.next()).unwrap_or(self.null_to);
+			}
+			let hovered = ui.is_item_hovered();
+			changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &self.null_to, self.default.as_ref()) || changed;
+			if let Some(x) = self.changed { *x = *x || changed };
+		});
+	}
+}
+
+impl<'control> CharControlBuilder<'control> {
+	pub fn null_to(mut self, null_to: char) -> Self {
+		self.null_to = null_to;
 		self
 	}
 }
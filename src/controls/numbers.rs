@@ -1,10 +1,77 @@
+use crate::prelude::*;
+
+/// Drag for integers that don't fit into imgui's i32: drags a delta and applies it in i128 so nothing wraps
+pub(crate) fn drag_wide(ui: &imgui::Ui, label: &imgui::ImStr, value: &mut i128, speed: f32, min: i128, max: i128) -> bool {
+	let mut delta = 0;
+	let changed = ui.drag_int(label, &mut delta).speed(speed).display_format(&im_str!("{}", value)).build();
+	if changed {
+		*value = value.saturating_add(i128::from(delta)).max(min).min(max);
+	}
+	changed && delta != 0
+}
+
+/// Exact text entry for wide integers, out of range or malformed input is rejected with a popup
+pub(crate) fn input_wide(ui: &imgui::Ui, label: &imgui::ImStr, value: &mut i128, min: i128, max: i128) -> bool {
+	let mut changed = false;
+	let id = ui.push_id(label);
+	let mut buf = im_str!("{}", value);
+	if ui.input_text(label, &mut buf).chars_decimal(true).enter_returns_true(true).resize_buffer(true).build() {
+		match buf.to_str().trim().parse::<i128>() {
+			Ok(v) if v >= min && v <= max => {
+				*value = v;
+				changed = true;
+			},
+			_ => ui.open_popup(im_str!("invalid")),
+		}
+	}
+	ui.popup(im_str!("invalid"), || {
+		ui.text_colored([1., 0.3, 0.3, 1.], &im_str!("expected an integer in {} ..= {}", min, max));
+	});
+	id.pop(ui);
+	changed
+}
+
 macro_rules! numbers {
+	(@drag wide $type:ident $ui:ident $self:ident $label:ident) => {{
+		let label = $label;
+		let id = $ui.push_id(label);
+		let spacing = $ui.clone_style().item_inner_spacing[0];
+		let width = (($ui.window_size()[0] - spacing) * 0.65) / 2.;
+		let mut v = *$self.value as i128;
+
+		let token = $ui.push_item_width(width);
+		let mut changed = crate::controls::numbers::drag_wide($ui, im_str!("##drag"), &mut v, $self.speed, std::$type::MIN as i128, std::$type::MAX as i128);
+		let mut hovered = $ui.is_item_hovered();
+		$ui.same_line_with_spacing(0., spacing);
+		changed = crate::controls::numbers::input_wide($ui, im_str!("##exact"), &mut v, std::$type::MIN as i128, std::$type::MAX as i128) || changed;
+		hovered = hovered || $ui.is_item_hovered();
+		drop(token);
+		$ui.same_line_with_spacing(0., spacing);
+		$ui.text(crate::controls::visible_label(label));
+		id.pop($ui);
+
+		*$self.value = v as $type;
+		(changed, hovered)
+	}};
+	(@drag int $type:ident $ui:ident $self:ident $label:ident) => {{
+		let mut v = *$self.value as _;
+		let changed = $ui.drag_int($label, &mut v).speed($self.speed).min(std::$type::MIN as _).max(std::$type::MAX as _).build();
+		*$self.value = v as _;
+		(changed, $ui.is_item_hovered())
+	}};
+	(@drag float $type:ident $ui:ident $self:ident $label:ident) => {{
+		let mut v = *$self.value as _;
+		let changed = $ui.drag_float($label, &mut v).speed($self.speed).min(std::$type::MIN as _).max(std::$type::MAX as _).build();
+		*$self.value = v as _;
+		(changed, $ui.is_item_hovered())
+	}};
 	($([$type:ident $kind:ident]),+$(,)*) => {$(paste::item!{
-		mod $type {
-			use crate::prelude::*;
+		pub mod $type {
+			use crate::{controls::expression, prelude::*};
 
 			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut $type {
 				type SystemData = ();
+				type State = ();
 				type Builder = Builder<'control>;
 			}
 
@@ -13,12 +80,13 @@ macro_rules! numbers {
 				pub label: Option<&'control imgui::ImStr>,
 				pub speed: f32,
 				pub null_to: $type,
+				pub default: Option<$type>,
 				pub changed: Option<&'control mut bool>,
 			}
 
 			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut $type> for Builder<'control> {
 				fn new(value: &'control mut $type) -> Self {
-					Self { value, label: None, speed: 1., null_to: <$type as Default>::default(), changed: None }
+					Self { value, label: None, speed: 1., null_to: <$type as Default>::default(), default: None, changed: None }
 				}
 				fn label(mut self, label: &'control imgui::ImStr) -> Self {
 					self.label = Some(label);
@@ -28,15 +96,22 @@ macro_rules! numbers {
 					self.changed = Some(changed);
 					self
 				}
+				fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+					self.default = default.downcast_ref::<$type>().cloned();
+					self
+				}
 				fn build(self) {
 					amethyst_imgui::with(|ui| {
-						let mut v = *self.value as _;
-						let mut changed = ui.[<drag_$kind>](self.label.unwrap(), &mut v).speed(self.speed).min(std::$type::MIN as _).max(std::$type::MAX as _).build();
-						*self.value = v as _;
-						if ui.is_item_hovered() && ui.is_mouse_down(imgui::MouseButton::Right) {
-							changed = true;
-							*self.value = self.null_to;
-						}
+						let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+						let (mut changed, hovered) = match expression::entry(ui, label, self.value) {
+							Some(changed) => (changed, ui.is_item_hovered()),
+							None => {
+								let (changed, hovered) = numbers!(@drag $kind $type ui self label);
+								expression::ctrl_click(ui, hovered, self.value);
+								(changed, hovered)
+							},
+						};
+						changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &self.null_to, self.default.as_ref()) || changed;
 						if let Some(x) = self.changed { *x = *x || changed };
 					});
 				}
@@ -60,12 +135,12 @@ numbers![
 	[u8 int],
 	[u16 int],
 	[u32 int],
-	[u64 int],
-	[usize int],
+	[u64 wide],
+	[usize wide],
 	[i8 int],
 	[i16 int],
 	[i32 int],
-	[i64 int],
+	[i64 wide],
 	[f32 float],
 	[f64 float],
 ];
@@ -0,0 +1,167 @@
+use crate::prelude::*;
+use std::ops::{Range, RangeInclusive};
+
+/// Draws `start` and `end` side by side, whichever one wasn't touched gets pushed along so `start <= end` holds
+///
+/// Returns whether either changed and whether the label is hovered, the ends have their own context menus so the whole range's opens from the label.
+fn draw_range<T: PartialOrd + Copy>(
+	ui: &imgui::Ui,
+	label: &imgui::ImStr,
+	start: &mut T,
+	end: &mut T,
+	mut draw: impl FnMut(&mut T, &imgui::ImStr) -> bool,
+) -> (bool, bool) {
+	let id = ui.push_id(label);
+	let spacing = ui.clone_style().item_inner_spacing[0];
+	let width = ((ui.window_size()[0] - spacing * 1.5) * 0.65) / 2.;
+
+	let token = ui.push_item_width(width);
+	let start_changed = draw(start, im_str!("##start"));
+	ui.same_line_with_spacing(0., spacing);
+	let end_changed = draw(end, im_str!("##end"));
+	drop(token);
+
+	if *start > *end {
+		if start_changed {
+			*end = *start;
+		} else {
+			*start = *end;
+		}
+	}
+
+	ui.same_line_with_spacing(0., spacing);
+	ui.text(crate::controls::visible_label(label));
+	let hovered = ui.is_item_hovered();
+	id.pop(ui);
+
+	(start_changed || end_changed, hovered)
+}
+
+macro_rules! ranges {
+	($($type:ident),+$(,)*) => {$(paste::item!{
+		mod [<range_$type>] {
+			use super::*;
+
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut Range<$type> {
+				type SystemData = ();
+				type State = ();
+				type Builder = RangeControlBuilder<'control>;
+			}
+
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut RangeInclusive<$type> {
+				type SystemData = ();
+				type State = ();
+				type Builder = RangeInclusiveControlBuilder<'control>;
+			}
+
+			pub struct RangeControlBuilder<'control> {
+				pub value: &'control mut Range<$type>,
+				pub label: Option<&'control imgui::ImStr>,
+				pub speed: f32,
+				pub null_to: Range<$type>,
+				pub default: Option<Range<$type>>,
+				pub changed: Option<&'control mut bool>,
+			}
+
+			pub struct RangeInclusiveControlBuilder<'control> {
+				pub value: &'control mut RangeInclusive<$type>,
+				pub label: Option<&'control imgui::ImStr>,
+				pub speed: f32,
+				pub null_to: RangeInclusive<$type>,
+				pub default: Option<RangeInclusive<$type>>,
+				pub changed: Option<&'control mut bool>,
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut Range<$type>> for RangeControlBuilder<'control> {
+				fn new(value: &'control mut Range<$type>) -> Self {
+					Self { value, label: None, speed: 1., null_to: <$type as Default>::default() .. <$type as Default>::default(), default: None, changed: None }
+				}
+				fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+					self.default = default.downcast_ref::<Range<$type>>().cloned();
+					self
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) {
+					amethyst_imgui::with(|ui| {
+						let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+						let speed = self.speed;
+						let Range { start, end } = &mut *self.value;
+						let (mut changed, hovered) = draw_range(ui, label, start, end, |v, label| {
+							let mut changed = false;
+							v.control().speed(speed).label(label).changed(&mut changed).build();
+							changed
+						});
+						changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &self.null_to, self.default.as_ref()) || changed;
+						if let Some(x) = self.changed { *x = *x || changed };
+					});
+				}
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut RangeInclusive<$type>> for RangeInclusiveControlBuilder<'control> {
+				fn new(value: &'control mut RangeInclusive<$type>) -> Self {
+					Self { value, label: None, speed: 1., null_to: <$type as Default>::default() ..= <$type as Default>::default(), default: None, changed: None }
+				}
+				fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+					self.default = default.downcast_ref::<RangeInclusive<$type>>().cloned();
+					self
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) {
+					amethyst_imgui::with(|ui| {
+						let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+						let speed = self.speed;
+						let (mut start, mut end) = (*self.value.start(), *self.value.end());
+						let (mut changed, hovered) = draw_range(ui, label, &mut start, &mut end, |v, label| {
+							let mut changed = false;
+							v.control().speed(speed).label(label).changed(&mut changed).build();
+							changed
+						});
+						if changed {
+							*self.value = start ..= end;
+						}
+						changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &self.null_to, self.default.as_ref()) || changed;
+						if let Some(x) = self.changed { *x = *x || changed };
+					});
+				}
+			}
+
+			impl<'control> RangeControlBuilder<'control> {
+				pub fn speed(mut self, speed: f32) -> Self {
+					self.speed = speed;
+					self
+				}
+				pub fn null_to(mut self, null_to: Range<$type>) -> Self {
+					self.null_to = null_to;
+					self
+				}
+			}
+
+			impl<'control> RangeInclusiveControlBuilder<'control> {
+				pub fn speed(mut self, speed: f32) -> Self {
+					self.speed = speed;
+					self
+				}
+				pub fn null_to(mut self, null_to: RangeInclusive<$type>) -> Self {
+					self.null_to = null_to;
+					self
+				}
+			}
+		}
+	})+};
+}
+
+ranges![u8, u16, u32, u64, usize, i8, i16, i32, i64, f32, f64];
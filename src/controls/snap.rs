@@ -0,0 +1,121 @@
+use crate::prelude::*;
+use std::sync::{Mutex, PoisonError};
+
+/// Key that flips snapping while it's held
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapModifier {
+	Shift,
+	Ctrl,
+	Alt,
+}
+
+impl SnapModifier {
+	const ALL: [SnapModifier; 3] = [SnapModifier::Shift, SnapModifier::Ctrl, SnapModifier::Alt];
+
+	fn name(self) -> &'static imgui::ImStr {
+		match self {
+			SnapModifier::Shift => im_str!("shift"),
+			SnapModifier::Ctrl => im_str!("ctrl"),
+			SnapModifier::Alt => im_str!("alt"),
+		}
+	}
+
+	fn held(self, ui: &imgui::Ui) -> bool {
+		let io = ui.io();
+		match self {
+			SnapModifier::Shift => io.key_shift,
+			SnapModifier::Ctrl => io.key_ctrl,
+			SnapModifier::Alt => io.key_alt,
+		}
+	}
+}
+
+/// Which of the steps in `SnapSettings` a control rounds to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapTo {
+	Translation,
+	Rotation,
+	Scale,
+}
+
+/// Steps that dragged transforms are rounded to, edited from the inspector's "snapping" panel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapSettings {
+	/// Snap without holding the modifier, holding it then turns snapping off instead
+	pub always: bool,
+	pub modifier: SnapModifier,
+	/// Grid size for translations
+	pub translation: f32,
+	/// Increment in degrees
+	pub rotation: f32,
+	pub scale: f32,
+}
+
+impl Default for SnapSettings {
+	fn default() -> Self { Self::DEFAULT }
+}
+
+// controls can't reach the inspector's resources, so it hands the settings over every frame
+static SETTINGS: Mutex<SnapSettings> = Mutex::new(SnapSettings::DEFAULT);
+// key, unsnapped value and last snapped value of the field being dragged, only one is dragged at a time
+static DRAGGING: Mutex<Option<(usize, f64, f64)>> = Mutex::new(None);
+
+impl SnapSettings {
+	const DEFAULT: Self = Self { always: false, modifier: SnapModifier::Shift, translation: 1., rotation: 15., scale: 0.1 };
+
+	pub(crate) fn publish(self, ui: &imgui::Ui) {
+		*SETTINGS.lock().unwrap_or_else(PoisonError::into_inner) = self;
+		// a new drag starts from the field's value, not from what was left over from the last one
+		if !ui.is_mouse_down(imgui::MouseButton::Left) {
+			*DRAGGING.lock().unwrap_or_else(PoisonError::into_inner) = None;
+		}
+	}
+
+	/// Step a control snapping to `to` rounds to this frame, if any
+	pub(crate) fn step(ui: &imgui::Ui, to: SnapTo) -> Option<f64> {
+		let settings = *SETTINGS.lock().unwrap_or_else(PoisonError::into_inner);
+		settings.active_step(ui, to)
+	}
+
+	/// Step for `to` if snapping is on with these settings, for code that has them at hand
+	pub(crate) fn active_step(&self, ui: &imgui::Ui, to: SnapTo) -> Option<f64> {
+		if self.always == self.modifier.held(ui) {
+			return None;
+		}
+		let step = match to {
+			SnapTo::Translation => self.translation,
+			SnapTo::Rotation => self.rotation,
+			SnapTo::Scale => self.scale,
+		};
+		Some(f64::from(step)).filter(|&x| x > 0.)
+	}
+
+	pub(crate) fn draw(&mut self, ui: &imgui::Ui) {
+		let id = ui.push_id(im_str!("snapping"));
+		ui.checkbox(im_str!("always snap"), &mut self.always);
+		let mut current = SnapModifier::ALL.iter().position(|&x| x == self.modifier).unwrap_or(0);
+		let items = SnapModifier::ALL.iter().map(|x| x.name()).collect::<Vec<_>>();
+		if imgui::ComboBox::new(im_str!("toggle with")).build_simple_string(ui, &mut current, items.as_slice()) {
+			self.modifier = SnapModifier::ALL[current];
+		}
+		ui.drag_float(im_str!("grid"), &mut self.translation).speed(0.01).min(0.).build();
+		ui.drag_float(im_str!("rotation"), &mut self.rotation).speed(0.1).min(0.).display_format(im_str!("%.1f deg")).build();
+		ui.drag_float(im_str!("scale step"), &mut self.scale).speed(0.001).min(0.).build();
+		id.pop(ui);
+	}
+}
+
+/// Round a dragged value to `step`, small drags add up on the unsnapped value instead of being rounded away
+///
+/// `key` tells fields apart, e.g. the address of the value.
+pub(crate) fn round(key: usize, before: f64, after: f64, step: f64) -> f64 {
+	let mut dragging = DRAGGING.lock().unwrap_or_else(PoisonError::into_inner);
+	let raw = match *dragging {
+		// f32 fields don't hold the snapped value exactly
+		Some((k, raw, snapped)) if k == key && (snapped - before).abs() <= step * 1e-4 => raw + (after - before),
+		_ => after,
+	};
+	let snapped = (raw / step).round() * step;
+	*dragging = Some((key, raw, snapped));
+	snapped
+}
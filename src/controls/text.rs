@@ -0,0 +1,126 @@
+use crate::prelude::*;
+
+trait Text: Sized {
+	fn to_im(&self) -> imgui::ImString;
+	fn from_im(buf: imgui::ImString) -> Self;
+}
+
+impl Text for String {
+	fn to_im(&self) -> imgui::ImString { imgui::ImString::new(self.clone()) }
+	fn from_im(buf: imgui::ImString) -> Self { buf.to_str().to_owned() }
+}
+
+impl Text for imgui::ImString {
+	fn to_im(&self) -> imgui::ImString { self.clone() }
+	fn from_im(buf: imgui::ImString) -> Self { buf }
+}
+
+fn text_input(
+	ui: &imgui::Ui,
+	label: &imgui::ImStr,
+	buf: &mut imgui::ImString,
+	multiline: bool,
+	max_length: Option<usize>,
+	hint: Option<&imgui::ImStr>,
+) -> bool {
+	let mut changed = if multiline {
+		let height = ui.text_line_height_with_spacing() * 4.;
+		ui.input_text_multiline(label, buf, [0., height]).resize_buffer(true).build()
+	} else {
+		ui.input_text(label, buf).resize_buffer(true).build()
+	};
+
+	if let Some(max_length) = max_length {
+		if let Some((i, _)) = buf.to_str().char_indices().nth(max_length) {
+			let truncated = buf.to_str()[.. i].to_owned();
+			*buf = imgui::ImString::new(truncated);
+			changed = true;
+		}
+	}
+
+	if let Some(hint) = hint {
+		if buf.is_empty() && !ui.is_item_active() {
+			let padding = ui.clone_style().frame_padding;
+			let min = ui.item_rect_min();
+			ui.get_window_draw_list().add_text([min[0] + padding[0], min[1] + padding[1]], ui.style_color(imgui::StyleColor::TextDisabled), hint);
+		}
+	}
+
+	changed
+}
+
+macro_rules! strings {
+	($([$type:ty, $builder:ident]),+$(,)*) => {$(
+		impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut $type {
+			type SystemData = ();
+			type State = ();
+			type Builder = $builder<'control>;
+		}
+
+		pub struct $builder<'control> {
+			pub value: &'control mut $type,
+			pub label: Option<&'control imgui::ImStr>,
+			pub null_to: $type,
+			pub default: Option<$type>,
+			pub multiline: bool,
+			pub max_length: Option<usize>,
+			pub hint: Option<&'control imgui::ImStr>,
+			pub changed: Option<&'control mut bool>,
+		}
+
+		impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut $type> for $builder<'control> {
+			fn new(value: &'control mut $type) -> Self {
+				Self { value, label: None, null_to: <$type as Default>::default(), default: None, multiline: false, max_length: None, hint: None, changed: None }
+			}
+			fn label(mut self, label: &'control imgui::ImStr) -> Self {
+				self.label = Some(label);
+				self
+			}
+			fn changed(mut self, changed: &'control mut bool) -> Self {
+				self.changed = Some(changed);
+				self
+			}
+			fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+				self.default = default.downcast_ref::<$type>().cloned();
+				self
+			}
+			fn build(self) {
+				amethyst_imgui::with(|ui| {
+					let mut buf = self.value.to_im();
+					let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
+					let mut changed = text_input(ui, label, &mut buf, self.multiline, self.max_length, self.hint);
+					if changed {
+						*self.value = Text::from_im(buf);
+					}
+					let hovered = ui.is_item_hovered();
+					changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &self.null_to, self.default.as_ref()) || changed;
+					if let Some(x) = self.changed { *x = *x || changed };
+				});
+			}
+		}
+
+		impl<'control> $builder<'control> {
+			pub fn null_to(mut self, null_to: &str) -> Self {
+				self.null_to = Text::from_im(imgui::ImString::new(null_to));
+				self
+			}
+			pub fn multiline(mut self, multiline: bool) -> Self {
+				self.multiline = multiline;
+				self
+			}
+			pub fn max_length(mut self, max_length: usize) -> Self {
+				self.max_length = Some(max_length);
+				self
+			}
+			pub fn hint(mut self, hint: &'control imgui::ImStr) -> Self {
+				self.hint = Some(hint);
+				self
+			}
+		}
+	)+};
+}
+
+strings![
+	[String, StringControlBuilder],
+	[imgui::ImString, ImStringControlBuilder],
+];
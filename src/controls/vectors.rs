@@ -1,24 +1,75 @@
+use crate::prelude::*;
+
+/// Name and colour of the `i`th axis
+pub(crate) fn axis(i: usize) -> (&'static imgui::ImStr, [f32; 4]) {
+	match i {
+		0 => (im_str!("X"), [0.9, 0.35, 0.35, 1.]),
+		1 => (im_str!("Y"), [0.45, 0.8, 0.35, 1.]),
+		2 => (im_str!("Z"), [0.35, 0.55, 1., 1.]),
+		_ => (im_str!("W"), [0.75, 0.75, 0.75, 1.]),
+	}
+}
+
 macro_rules! vectors {
+	(@drag wide $type:ident $ui:ident $value:expr, $speed:expr) => {{
+		let mut v = $value as i128;
+		let changed = crate::controls::numbers::drag_wide($ui, im_str!(""), &mut v, $speed, std::$type::MIN as i128, std::$type::MAX as i128);
+		$value = v as $type;
+		changed
+	}};
+	(@drag int $type:ident $ui:ident $value:expr, $speed:expr) => {{
+		let mut v = $value as _;
+		let changed = $ui.drag_int(im_str!(""), &mut v).speed($speed).min(std::$type::MIN as _).max(std::$type::MAX as _).build();
+		$value = v as _;
+		changed
+	}};
+	(@drag float $type:ident $ui:ident $value:expr, $speed:expr) => {{
+		let mut v = $value as _;
+		let changed = $ui.drag_float(im_str!(""), &mut v).speed($speed).min(std::$type::MIN as _).max(std::$type::MAX as _).build();
+		$value = v as _;
+		changed
+	}};
 	($([$size:tt $type:ident $kind:ident]),+$(,)*) => {$(paste::item!{
 		mod [<$kind$type$size>] {
-			use crate::prelude::*;
+			use crate::{controls::{expression::{self, Expression}, snap::{self, SnapSettings, SnapTo}, vectors::axis}, prelude::*};
 
 			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut [<Vector$size>]<$type> {
 				type SystemData = ();
+				/// Whether the proportional lock is on
+				type State = bool;
 				type Builder = Builder<'control>;
 			}
 
-			pub struct Builder<'control> {
+			/// `V` is the type being edited, points and translations are drawn as the vector they wrap
+			pub struct Builder<'control, V = [<Vector$size>]<$type>> {
 				pub value: &'control mut [<Vector$size>]<$type>,
 				pub label: Option<&'control imgui::ImStr>,
 				pub speed: f32,
 				pub null_to: $type,
+				pub default: Option<[<Vector$size>]<$type>>,
+				/// Show a lock that scales all components together
+				pub proportional: bool,
+				/// Round drags to one of the inspector's snapping steps while snapping is on
+				pub snap: Option<SnapTo>,
+				pub state: Option<&'control mut bool>,
 				pub changed: Option<&'control mut bool>,
+				pub edits: std::marker::PhantomData<V>,
+			}
+
+			impl crate::controls::context::ClipboardText for [<Vector$size>]<$type> {
+				fn to_clipboard(&self) -> String { crate::controls::context::to_list(self.iter()) }
+				fn from_clipboard(text: &str) -> Option<Self> {
+					crate::controls::context::from_list::<$type>(text, $size).map(|x| Self::from_column_slice(&x))
+				}
 			}
 
 			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut [<Vector$size>]<$type>> for Builder<'control> {
 				fn new(value: &'control mut [<Vector$size>]<$type>) -> Self {
-					Self { value, label: None, speed: 1., null_to: <$type as Default>::default(), changed: None }
+					Self { value, label: None, speed: 1., null_to: <$type as Default>::default(), default: None, proportional: false, snap: None, state: None, changed: None, edits: std::marker::PhantomData }
+				}
+				fn state(mut self, state: &'control mut bool) -> Self {
+					self.state = Some(state);
+					self
 				}
 				fn label(mut self, label: &'control imgui::ImStr) -> Self {
 					self.label = Some(label);
@@ -28,38 +79,161 @@ macro_rules! vectors {
 					self.changed = Some(changed);
 					self
 				}
+				fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+					self.default = default.downcast_ref::<[<Vector$size>]<$type>>().cloned();
+					self
+				}
 				fn build(self) {
 					amethyst_imgui::with(|ui| {
 						let mut changed = false;
-						let label = self.label.unwrap();
+						let mut hovered = false;
+						let label: &imgui::ImStr = &crate::controls::label_or_id(self.label, &*self.value);
 						let id = ui.push_id(label);
 
-						let spacing = ui.clone_style().item_inner_spacing[0];
-						let width = ((ui.window_size()[0] - spacing * (($size - 1) as f32 * 1.5)) * 0.65) / $size as f32;
+						let style = ui.clone_style();
+						let spacing = style.item_inner_spacing[0];
+						let mut fallback = false;
+						let locked = match self.state { Some(state) => state, None => &mut fallback };
+						let lock_width = if self.proportional { ui.calc_text_size(im_str!("unlocked"), false, 0.)[0] + style.frame_padding[0] * 2. + spacing } else { 0. };
+						let axis_width = ui.calc_text_size(im_str!("W"), false, 0.)[0] + spacing;
+						let label_width = ui.calc_text_size(label, true, 0.)[0];
+						let width = ((ui.content_region_avail()[0] - label_width - lock_width) / $size as f32 - axis_width - spacing).max(1.);
 
+						let before = *self.value;
+						let step = self.snap.and_then(|to| SnapSettings::step(ui, to));
 						for i in 0 .. $size {
 							let inner_id = ui.push_id(i as i32);
+							let (axis, color) = axis(i);
+							ui.text_colored(color, axis);
+							hovered = hovered || ui.is_item_hovered();
+							ui.same_line_with_spacing(0., spacing);
+
 							let token = ui.push_item_width(width);
-							let mut v = self.value[i as usize] as _;
-							changed = ui.[<drag_$kind>](im_str!(""), &mut v).speed(self.speed).min(std::$type::MIN as _).max(std::$type::MAX as _).build() || changed;
-							self.value[i as usize] = v as _;
-							if ui.is_item_hovered() && ui.is_mouse_down(imgui::MouseButton::Right) {
-								changed = true;
-								self.value[i as usize] = self.null_to;
+							match expression::entry(ui, im_str!(""), &mut self.value[i as usize]) {
+								Some(entered) => changed = entered || changed,
+								None => {
+									let dragged = vectors!(@drag $kind $type ui self.value[i as usize], self.speed);
+									if let (true, Some(step)) = (dragged, step) {
+										let key = &self.value[i as usize] as *const _ as usize;
+										self.value[i as usize] = <$type as Expression>::from_f64(snap::round(key, before[i].to_f64(), self.value[i as usize].to_f64(), step));
+									}
+									changed = dragged || changed;
+									expression::ctrl_click(ui, ui.is_item_hovered(), &self.value[i as usize]);
+								},
 							}
+							hovered = hovered || ui.is_item_hovered();
 							ui.same_line_with_spacing(0., spacing);
 							drop(token);
 							inner_id.pop(ui);
 						}
 
-						ui.text(label);
+						// scale the other components by however much the edited one changed, there's no ratio to keep from zero
+						if self.proportional && *locked && changed {
+							if let Some(edited) = (0 .. $size).find(|&i| self.value[i] != before[i]) {
+								let from = before[edited].to_f64();
+								if from != 0. {
+									let ratio = self.value[edited].to_f64() / from;
+									for i in (0 .. $size).filter(|&i| i != edited) {
+										self.value[i] = <$type as expression::Expression>::from_f64(before[i].to_f64() * ratio);
+									}
+								}
+							}
+						}
+
+						if self.proportional {
+							if ui.small_button(if *locked { im_str!("locked") } else { im_str!("unlocked") }) {
+								*locked = !*locked;
+							}
+							ui.same_line_with_spacing(0., spacing);
+						}
+
+						ui.text(crate::controls::visible_label(label));
+						hovered = hovered || ui.is_item_hovered();
 						id.pop(ui);
+
+						let null_to = [<Vector$size>]::<$type>::repeat(self.null_to);
+						changed = crate::controls::context::context_menu(ui, label, hovered, self.value, &null_to, self.default.as_ref()) || changed;
 						if let Some(x) = self.changed { *x = *x || changed };
 					});
 				}
 			}
 
-			impl<'control> Builder<'control> {
+			// points and translations are just a wrapped vector, so they share the builder under a different `V`
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut [<Point$size>]<$type> {
+				type SystemData = ();
+				/// Whether the proportional lock is on
+				type State = bool;
+				type Builder = Builder<'control, [<Point$size>]<$type>>;
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut [<Point$size>]<$type>> for Builder<'control, [<Point$size>]<$type>> {
+				fn new(value: &'control mut [<Point$size>]<$type>) -> Self {
+					<Builder<'control> as InspectControlBuilder<'control, 'resource, &'control mut [<Vector$size>]<$type>>>::new(&mut value.coords).retype()
+				}
+				fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+					self.default = default.downcast_ref::<[<Point$size>]<$type>>().map(|x| x.coords.clone());
+					self
+				}
+				fn state(mut self, state: &'control mut bool) -> Self {
+					self.state = Some(state);
+					self
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) { <Builder<'control> as InspectControlBuilder<'control, 'resource, &'control mut [<Vector$size>]<$type>>>::build(self.retype()) }
+			}
+
+			impl<'control, 'resource: 'control> InspectControl<'control, 'resource> for &'control mut [<Translation$size>]<$type> {
+				type SystemData = ();
+				/// Whether the proportional lock is on
+				type State = bool;
+				type Builder = Builder<'control, [<Translation$size>]<$type>>;
+			}
+
+			impl<'control, 'resource: 'control> InspectControlBuilder<'control, 'resource, &'control mut [<Translation$size>]<$type>> for Builder<'control, [<Translation$size>]<$type>> {
+				fn new(value: &'control mut [<Translation$size>]<$type>) -> Self {
+					<Builder<'control> as InspectControlBuilder<'control, 'resource, &'control mut [<Vector$size>]<$type>>>::new(&mut value.vector).retype()
+				}
+				fn default_to(mut self, default: &'control dyn std::any::Any) -> Self {
+					self.default = default.downcast_ref::<[<Translation$size>]<$type>>().map(|x| x.vector.clone());
+					self
+				}
+				fn state(mut self, state: &'control mut bool) -> Self {
+					self.state = Some(state);
+					self
+				}
+				fn label(mut self, label: &'control imgui::ImStr) -> Self {
+					self.label = Some(label);
+					self
+				}
+				fn changed(mut self, changed: &'control mut bool) -> Self {
+					self.changed = Some(changed);
+					self
+				}
+				fn build(self) { <Builder<'control> as InspectControlBuilder<'control, 'resource, &'control mut [<Vector$size>]<$type>>>::build(self.retype()) }
+			}
+
+			impl<'control, V> Builder<'control, V> {
+				fn retype<W>(self) -> Builder<'control, W> {
+					Builder {
+						value: self.value,
+						label: self.label,
+						speed: self.speed,
+						null_to: self.null_to,
+						default: self.default,
+						proportional: self.proportional,
+						snap: self.snap,
+						state: self.state,
+						changed: self.changed,
+						edits: std::marker::PhantomData,
+					}
+				}
 				pub fn speed(mut self, speed: f32) -> Self {
 					self.speed = speed;
 					self
@@ -68,6 +242,19 @@ macro_rules! vectors {
 					self.null_to = null_to;
 					self
 				}
+				/// Add a lock toggle that keeps the ratio between components while dragging one of them
+				pub fn proportional(mut self) -> Self {
+					self.proportional = true;
+					self
+				}
+				/// Snap drags to the translation grid
+				pub fn snap(self) -> Self {
+					self.snap_to(SnapTo::Translation)
+				}
+				pub fn snap_to(mut self, to: SnapTo) -> Self {
+					self.snap = Some(to);
+					self
+				}
 			}
 		}
 	})+};
@@ -89,12 +276,12 @@ vectors![
 	[2 u32 int],
 	[3 u32 int],
 	[4 u32 int],
-	[2 u64 int],
-	[3 u64 int],
-	[4 u64 int],
-	[2 usize int],
-	[3 usize int],
-	[4 usize int],
+	[2 u64 wide],
+	[3 u64 wide],
+	[4 u64 wide],
+	[2 usize wide],
+	[3 usize wide],
+	[4 usize wide],
 	[2 i8 int],
 	[3 i8 int],
 	[4 i8 int],
@@ -104,7 +291,7 @@ vectors![
 	[2 i32 int],
 	[3 i32 int],
 	[4 i32 int],
-	[2 i64 int],
-	[3 i64 int],
-	[4 i64 int],
+	[2 i64 wide],
+	[3 i64 wide],
+	[4 i64 wide],
 ];
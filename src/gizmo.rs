@@ -0,0 +1,326 @@
+use amethyst::{
+	core::{
+		math::{Matrix4, Point3, Unit, UnitQuaternion, Vector2, Vector3},
+		Parent,
+		Transform,
+	},
+	ecs::prelude::*,
+	renderer::{debug_drawing::DebugLines, palette::Srgba, ActiveCamera, Camera},
+	window::ScreenDimensions,
+};
+use amethyst_imgui::imgui::{self, im_str};
+use crate::{
+	controls::vectors::axis,
+	inspectors::Transform::{decompose, is_uniform, parent_matrix},
+	SnapTo,
+};
+
+/// What dragging the handles of `TransformGizmo` does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GizmoMode {
+	Translate,
+	Rotate,
+	Scale,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Handle {
+	/// Move or scale along one of the entity's axes
+	Axis(usize),
+	/// Move in the entity's XY plane, or scale every axis together
+	Plane,
+	/// Rotate around the entity's Z axis
+	Ring,
+}
+
+/// Where the selected entity's handles are this frame
+struct Frame {
+	origin: Point3<f32>,
+	/// The entity's axes in world space, one handle long
+	axes: [Vector3<f32>; 3],
+	/// `origin` and `axes` in screen pixels
+	screen_origin: Vector2<f32>,
+	screen_axes: [Vector2<f32>; 3],
+	/// World units per handle
+	length: f32,
+}
+
+#[derive(Clone)]
+struct Drag {
+	entity: Entity,
+	handle: Handle,
+	mouse: Vector2<f32>,
+	/// World transform when the drag started, edits are made from it rather than added up every frame
+	world: Transform,
+	/// Mouse position last frame and the angle it turned around the origin since the drag started, added up frame by
+	/// frame so the ring keeps turning past half a turn
+	previous: Vector2<f32>,
+	turned: f32,
+	parent: Matrix4<f32>,
+	screen_origin: Vector2<f32>,
+	screen_axes: [Vector2<f32>; 3],
+	length: f32,
+}
+
+/// Draws handles on the selected entity's `Transform` with `DebugLines` and lets them be dragged in the game view
+///
+/// Needs `RenderDebugLines` in the render bundle and an entity with a `Camera`, edits go through `LazyUpdate` like the inspector's.
+/// Entities under a parent that scales unevenly get no handles, dragging them in world space would shear them.
+pub struct TransformGizmo {
+	pub mode: GizmoMode,
+	/// Length of the handles in pixels
+	pub size: f32,
+	drag: Option<Drag>,
+}
+
+impl Default for TransformGizmo {
+	fn default() -> Self {
+		Self { mode: GizmoMode::Translate, size: 100., drag: None }
+	}
+}
+
+/// Pixels from `point` to the closest segment of `line`
+fn distance(point: Vector2<f32>, line: &[Vector2<f32>]) -> f32 {
+	line.windows(2).map(|segment| {
+		let (a, b) = (segment[0], segment[1]);
+		let ab = b - a;
+		let t = if ab.norm_squared() > 0. { ((point - a).dot(&ab) / ab.norm_squared()).max(0.).min(1.) } else { 0. };
+		(a + ab * t - point).norm()
+	}).fold(std::f32::MAX, f32::min)
+}
+
+/// Mouse position in the physical pixels cameras project to, imgui's are logical
+pub(crate) fn mouse_position(ui: &imgui::Ui, screen: &ScreenDimensions) -> Vector2<f32> {
+	let io = ui.io();
+	Vector2::new(io.mouse_pos[0] * screen.width() / io.display_size[0], io.mouse_pos[1] * screen.height() / io.display_size[1])
+}
+
+/// The active camera, or any camera if none is set as active
+pub(crate) fn find_camera<'a>(
+	active_camera: &ActiveCamera,
+	cameras: &'a ReadStorage<'_, Camera>,
+	transforms: &'a ReadStorage<'_, Transform>,
+) -> Option<(&'a Camera, &'a Transform)> {
+	active_camera.entity
+		.and_then(|entity| Some((cameras.get(entity)?, transforms.get(entity)?)))
+		.or_else(|| (cameras, transforms).join().next())
+}
+
+fn snap(value: f32, step: Option<f64>) -> f32 {
+	match step {
+		Some(step) => ((f64::from(value) / step).round() * step) as f32,
+		None => value,
+	}
+}
+
+impl TransformGizmo {
+	/// World space lines of every handle in the current mode
+	fn handles(&self, frame: &Frame) -> Vec<(Handle, Vec<Point3<f32>>)> {
+		let o = frame.origin;
+		let [x, y, _] = frame.axes;
+		let square = |from: f32, to: f32| vec![o + x * from + y * from, o + x * to + y * from, o + x * to + y * to, o + x * from + y * to, o + x * from + y * from];
+		// an axis pointing at the camera is a dot on screen and would only get in the way of the plane handle
+		let visible = (0 .. 3).filter(|&i| frame.screen_axes[i].norm() > self.size * 0.1);
+
+		match self.mode {
+			GizmoMode::Translate => visible
+				.map(|i| (Handle::Axis(i), vec![o, o + frame.axes[i]]))
+				.chain(Some((Handle::Plane, square(0.2, 0.4))))
+				.collect(),
+			GizmoMode::Rotate => {
+				let ring = (0 ..= 32).map(|i| {
+					let angle = i as f32 / 32. * std::f32::consts::PI * 2.;
+					o + x * angle.cos() + y * angle.sin()
+				}).collect();
+				vec![(Handle::Ring, ring)]
+			},
+			GizmoMode::Scale => visible
+				.map(|i| {
+					let axis = frame.axes[i];
+					let side = frame.axes[(i + 1) % 3] * 0.05;
+					let end = o + axis;
+					(Handle::Axis(i), vec![o, end, end + side, end + side - axis * 0.1, end - side - axis * 0.1, end - side, end])
+				})
+				.chain(Some((Handle::Plane, square(-0.1, 0.1))))
+				.collect(),
+		}
+	}
+
+	/// World transform for the mouse being at `mouse` during `drag`
+	fn dragged(&self, drag: &Drag, mouse: Vector2<f32>, step: impl Fn(SnapTo) -> Option<f64>) -> Transform {
+		let delta = mouse - drag.mouse;
+		let mut world = drag.world.clone();
+		let rotation = *drag.world.rotation();
+		let unit = |i: usize| rotation * Vector3::ith(i, 1.);
+		// how many handle lengths the mouse moved along an axis on screen
+		let along = |i: usize| {
+			let axis = drag.screen_axes[i];
+			if axis.norm_squared() > 0. { delta.dot(&axis) / axis.norm_squared() } else { 0. }
+		};
+
+		match (self.mode, drag.handle) {
+			(GizmoMode::Translate, Handle::Axis(i)) => {
+				let distance = snap(along(i) * drag.length, step(SnapTo::Translation));
+				*world.translation_mut() += unit(i) * distance;
+			},
+			(GizmoMode::Translate, _) => {
+				// solve `delta = u * x + v * y` for the screen axes so the plane follows the mouse
+				let [x, y, _] = drag.screen_axes;
+				let det = x.x * y.y - x.y * y.x;
+				if det.abs() > std::f32::EPSILON {
+					let u = snap((delta.x * y.y - delta.y * y.x) / det * drag.length, step(SnapTo::Translation));
+					let v = snap((x.x * delta.y - x.y * delta.x) / det * drag.length, step(SnapTo::Translation));
+					*world.translation_mut() += unit(0) * u + unit(1) * v;
+				}
+			},
+			(GizmoMode::Rotate, _) => {
+				let mut angle = drag.turned;
+				// screen Y points down, flip unless the entity is seen from behind
+				let [x, y, _] = drag.screen_axes;
+				if x.x * y.y - x.y * y.x < 0. {
+					angle = -angle;
+				}
+				let angle = snap(angle.to_degrees(), step(SnapTo::Rotation)).to_radians();
+				world.set_rotation(UnitQuaternion::from_axis_angle(&Unit::new_normalize(unit(2)), angle) * rotation);
+			},
+			(GizmoMode::Scale, Handle::Axis(i)) => {
+				let scale = drag.world.scale()[i] * (1. + along(i));
+				world.scale_mut()[i] = snap(scale, step(SnapTo::Scale));
+			},
+			(GizmoMode::Scale, _) => {
+				let factor = 1. + delta.x / self.size;
+				let scale = drag.world.scale().map(|x| snap(x * factor, step(SnapTo::Scale)));
+				*world.scale_mut() = scale;
+			},
+		}
+
+		world
+	}
+}
+
+impl<'s> System<'s> for TransformGizmo {
+	type SystemData = (
+		Write<'s, crate::InspectorState>,
+		ReadStorage<'s, Transform>,
+		ReadStorage<'s, Parent>,
+		ReadStorage<'s, Camera>,
+		Read<'s, ActiveCamera>,
+		ReadExpect<'s, ScreenDimensions>,
+		Write<'s, DebugLines>,
+		Read<'s, LazyUpdate>,
+	);
+
+	fn run(&mut self, (mut inspector_state, transforms, parents, cameras, active_camera, screen, mut debug_lines, lazy): Self::SystemData) {
+		amethyst_imgui::with(|ui| {
+			inspector_state.over_gizmo = false;
+			let mode = &mut self.mode;
+			let mut switched = false;
+			imgui::Window::new(im_str!("Gizmo")).always_auto_resize(true).build(ui, || {
+				switched = ui.radio_button(im_str!("move"), mode, GizmoMode::Translate) || switched;
+				ui.same_line(0.);
+				switched = ui.radio_button(im_str!("rotate"), mode, GizmoMode::Rotate) || switched;
+				ui.same_line(0.);
+				switched = ui.radio_button(im_str!("scale"), mode, GizmoMode::Scale) || switched;
+			});
+			if switched {
+				self.drag = None;
+			}
+
+			let entity = match inspector_state.selected {
+				Some(entity) if transforms.contains(entity) => entity,
+				_ => {
+					self.drag = None;
+					return;
+				},
+			};
+			let (camera, camera_transform) = if let Some(x) = find_camera(&active_camera, &cameras, &transforms) { x } else { return; };
+
+			let diagonal = Vector2::new(screen.width(), screen.height());
+			let to_screen = |point: Point3<f32>| camera.world_to_screen(point, diagonal, camera_transform).coords;
+			let mouse = mouse_position(ui, &screen);
+
+			let parent = parent_matrix(&transforms, &parents, entity);
+			if !is_uniform(&parent) {
+				self.drag = None;
+				return;
+			}
+			let local = transforms.get(entity).unwrap_or_else(f!());
+			let world = decompose(&(parent * local.matrix()), local.scale());
+			let origin = Point3::from(*world.translation());
+			let screen_origin = to_screen(origin);
+			let units = [world.rotation() * Vector3::x(), world.rotation() * Vector3::y(), world.rotation() * Vector3::z()];
+			// keep the handles the same size on screen however far away the entity is
+			let pixels_per_unit = units.iter().map(|&x| (to_screen(origin + x) - screen_origin).norm()).fold(0., f32::max);
+			if pixels_per_unit <= std::f32::EPSILON {
+				return;
+			}
+			let length = self.size / pixels_per_unit;
+			let axes = [units[0] * length, units[1] * length, units[2] * length];
+			let frame = Frame {
+				origin,
+				axes,
+				screen_origin,
+				screen_axes: [to_screen(origin + axes[0]) - screen_origin, to_screen(origin + axes[1]) - screen_origin, to_screen(origin + axes[2]) - screen_origin],
+				length,
+			};
+
+			if self.drag.as_ref().map_or(false, |drag| drag.entity != entity || !ui.is_mouse_down(imgui::MouseButton::Left)) {
+				self.drag = None;
+			}
+
+			let handles = self.handles(&frame);
+			let hovered = if self.drag.is_none() && !ui.io().want_capture_mouse {
+				handles.iter()
+					.map(|(handle, line)| (*handle, distance(mouse, &line.iter().map(|&x| to_screen(x)).collect::<Vec<_>>())))
+					.filter(|&(_, distance)| distance < 6.)
+					.min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+					.map(|(handle, _)| handle)
+			} else {
+				None
+			};
+
+			if let (Some(handle), true) = (hovered, ui.is_mouse_clicked(imgui::MouseButton::Left)) {
+				self.drag = Some(Drag {
+					entity,
+					handle,
+					mouse,
+					world: world.clone(),
+					previous: mouse,
+					turned: 0.,
+					parent,
+					screen_origin: frame.screen_origin,
+					screen_axes: frame.screen_axes,
+					length: frame.length,
+				});
+			}
+
+			if let Some(drag) = self.drag.as_mut() {
+				let (from, to) = (drag.previous - drag.screen_origin, mouse - drag.screen_origin);
+				drag.turned += (from.x * to.y - from.y * to.x).atan2(from.dot(&to));
+				drag.previous = mouse;
+			}
+			if let Some(drag) = self.drag.as_ref().filter(|drag| drag.mouse != mouse) {
+				let dragged = self.dragged(drag, mouse, |to| inspector_state.snap.active_step(ui, to));
+				match drag.parent.try_inverse() {
+					Some(inverse) => lazy.insert(entity, decompose(&(inverse * dragged.matrix()), local.scale())),
+					None => amethyst::log::warn!("can't drag the gizmo, the parent's matrix isn't invertible"),
+				}
+			}
+
+			let active = self.drag.as_ref().map(|drag| drag.handle).or(hovered);
+			inspector_state.over_gizmo = active.is_some();
+			for (handle, line) in handles {
+				let color = match handle {
+					_ if active == Some(handle) => [1., 0.85, 0.2, 1.],
+					Handle::Axis(i) => axis(i).1,
+					Handle::Ring => axis(2).1,
+					Handle::Plane => axis(3).1,
+				};
+				let color = Srgba::new(color[0], color[1], color[2], color[3]);
+				for segment in line.windows(2) {
+					debug_lines.draw_line(segment[0], segment[1], color);
+				}
+			}
+		});
+	}
+}
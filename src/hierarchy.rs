@@ -78,6 +78,7 @@ impl<'s> System<'s> for InspectorHierarchy {
 
 	fn run(&mut self, (mut inspector_state, names, parents, hierarchy, entities, lazy): Self::SystemData) {
 		amethyst_imgui::with(move |ui| {
+			inspector_state.hovered = None;
 			imgui::Window::new(&im_str!("Hierarchy"))
 				.size([300.0, 500.0], imgui::Condition::FirstUseEver)
 				.build(ui, move || {
@@ -90,6 +91,7 @@ impl<'s> System<'s> for InspectorHierarchy {
 					for (entity, _) in (&entities, !&parents).join() {
 						self.render_boy(entity, &hierarchy, &names, &ui, &mut inspector_state, &entities, &lazy);
 					}
+					inspector_state.hovered = self.hovering;
 
 					let is_dragging = ui.is_mouse_dragging(imgui::MouseButton::Left);
 					let is_mouse_down = ui.is_mouse_down(imgui::MouseButton::Left);
@@ -3,7 +3,9 @@
 pub mod Tint;
 pub mod Named;
 pub mod SpriteRender;
+pub mod TextureHandle;
 pub mod Transform;
+pub mod UiImage;
 pub mod UiText;
 pub mod UiTransform;
-// pub mod UiTransformDebug;
+pub mod UiTransformDebug;
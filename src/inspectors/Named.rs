@@ -8,9 +8,12 @@ use imgui::im_str;
 
 impl<'a> Inspect<'a> for Named {
 	type SystemData = (ReadStorage<'a, Self>, Read<'a, LazyUpdate>);
+	type State = ();
+
+	fn copy(&self) -> Option<Self> { Some(self.clone()) }
 
 	fn can_add(_: &mut Self::SystemData, _: ::amethyst::ecs::Entity) -> bool { true }
-	fn inspect((storage, lazy): &mut Self::SystemData, entity: Entity) {
+	fn inspect((storage, lazy): &mut Self::SystemData, _: &mut Self::State, entity: Entity) {
 		amethyst_imgui::with(|ui| {
 			let me = if let Some(x) = storage.get(entity) { x } else { return; };
 			let mut buf = imgui::ImString::new(me.name.clone());
@@ -1,54 +1,51 @@
 use amethyst::{
 	ecs::prelude::*,
-	renderer::SpriteRender,
-	assets::AssetStorage,
+	renderer::{SpriteRender, SpriteSheet},
+	assets::{AssetStorage, Handle},
 };
 use amethyst_imgui::imgui;
-use crate::Inspect;
+use crate::{controls::assets::AssetList, Inspect, InspectControl, InspectControlBuilder};
 use imgui::im_str;
 
 /// Add this as a resource and insert your handles into it to get a dropdown for SpriteSheetHandle selection
-pub type SpriteList = std::collections::HashMap<String, amethyst::assets::Handle<amethyst::renderer::SpriteSheet>>;
+pub type SpriteList = AssetList<SpriteSheet>;
 
 impl<'a> Inspect<'a> for SpriteRender {
 	type SystemData = (
 		ReadStorage<'a, Self>,
-		ReadExpect<'a, AssetStorage<amethyst::renderer::SpriteSheet>>,
-		Read<'a, SpriteList>,
+		<&'a mut Handle<SpriteSheet> as InspectControl<'a, 'a>>::SystemData,
 		Read<'a, LazyUpdate>,
 	);
+	/// Search filter of the asset picker
+	type State = imgui::ImString;
 
-	fn inspect((storage, sprites, sprite_list, lazy): &mut Self::SystemData, entity: Entity) {
+	fn copy(&self) -> Option<Self> { Some(self.clone()) }
+
+	fn inspect((storage, sheet_data, lazy): &mut Self::SystemData, state: &mut Self::State, entity: Entity) {
 		amethyst_imgui::with(|ui| {
 			let me = if let Some(x) = storage.get(entity) { x } else { return; };
 			let mut new_me = me.clone();
 			let id = ui.push_id(im_str!("sprite_render"));
 			let mut changed = false;
 
-			if !sprite_list.is_empty() {
-				let mut current = 0;
-				let mut items = Vec::<imgui::ImString>::with_capacity(9);
-				let list_vec = sprite_list.iter().collect::<Vec<_>>();
-				for (i, (key, sprite_sheet)) in list_vec.iter().enumerate() {
-					if me.sprite_sheet == **sprite_sheet {
-						current = i;
-					}
-					items.push(im_str!("{}", key));
-				}
-
-				changed = imgui::ComboBox::new(im_str!("sprite sheet")).build_simple_string(ui, &mut current, items.iter().map(std::ops::Deref::deref).collect::<Vec<_>>().as_slice()) || changed;
-				new_me.sprite_sheet = list_vec[current as usize].1.clone();
-				if new_me.sprite_sheet != me.sprite_sheet {
-					new_me.sprite_number = 0;
-				}
+			new_me.sprite_sheet.control().data(sheet_data).state(&mut *state).label(im_str!("sprite sheet")).changed(&mut changed).build();
+			if new_me.sprite_sheet != me.sprite_sheet {
+				new_me.sprite_number = 0;
 			}
 
-			let mut sprite_number = new_me.sprite_number as i32;
-			changed = imgui::Slider::new(
-				im_str!("# sprite"),
-				0 ..= sprites.get(&new_me.sprite_sheet).unwrap_or_else(f!()).sprites.len() as i32 - 1,
-			).build(ui, &mut sprite_number) || changed;
-			new_me.sprite_number = sprite_number as usize;
+			let sprites: &AssetStorage<SpriteSheet> = &sheet_data.1;
+			match sprites.get(&new_me.sprite_sheet) {
+				Some(sheet) if sheet.sprites.is_empty() => ui.text_disabled(im_str!("# sprite: sheet has no sprites")),
+				Some(sheet) => {
+					let mut sprite_number = new_me.sprite_number as i32;
+					changed = imgui::Slider::new(
+						im_str!("# sprite"),
+						0 ..= sheet.sprites.len() as i32 - 1,
+					).build(ui, &mut sprite_number) || changed;
+					new_me.sprite_number = sprite_number as usize;
+				},
+				None => ui.text_disabled(im_str!("# sprite: sheet is loading")),
+			}
 
 			if changed {
 				lazy.insert(entity, new_me);
@@ -57,11 +54,14 @@ impl<'a> Inspect<'a> for SpriteRender {
 		});
 	}
 
-	fn can_add((_, _, sprite_list, _): &mut Self::SystemData, _: Entity) -> bool {
+	fn can_add((_, (sprite_list, _), _): &mut Self::SystemData, _: Entity) -> bool {
+		if sprite_list.is_empty() {
+			sprite_list.request();
+		}
 		!sprite_list.is_empty()
 	}
 
-	fn add((_, _, sprite_list, lazy): &mut Self::SystemData, entity: Entity) {
+	fn add((_, (sprite_list, _), lazy): &mut Self::SystemData, entity: Entity) {
 		lazy.insert(entity, SpriteRender { sprite_sheet: sprite_list.values().nth(0).unwrap_or_else(f!()).clone(), sprite_number: 0 });
 	}
 }
@@ -1,51 +1,50 @@
-use amethyst::ecs::prelude::*;
+use amethyst::{
+	assets::Handle,
+	ecs::prelude::*,
+	renderer::Texture,
+};
 use amethyst_imgui::imgui;
-use crate::Inspect;
+use crate::{controls::assets::AssetList, Inspect, InspectControl, InspectControlBuilder};
 use imgui::im_str;
 
 /// Add this as a resource and insert your handles into it to get a dropdown for TextureHandle selection
-pub type TextureList = std::collections::HashMap<String, amethyst::renderer::TextureHandle>;
+pub type TextureList = AssetList<Texture>;
 
-impl<'a> Inspect<'a> for amethyst::renderer::TextureHandle {
+impl<'a> Inspect<'a> for Handle<Texture> {
 	type SystemData = (
 		ReadStorage<'a, Self>,
-		Read<'a, TextureList>,
+		<&'a mut Handle<Texture> as InspectControl<'a, 'a>>::SystemData,
 		Read<'a, LazyUpdate>,
 	);
+	/// Search filter of the asset picker
+	type State = imgui::ImString;
 
-	fn inspect((storage, texture_list, lazy): &mut Self::SystemData, entity: Entity) {
+	fn copy(&self) -> Option<Self> { Some(self.clone()) }
+
+	fn inspect((storage, texture_data, lazy): &mut Self::SystemData, state: &mut Self::State, entity: Entity) {
 		amethyst_imgui::with(|ui| {
 			let me = if let Some(x) = storage.get(entity) { x } else { return; };
 			let mut new_me = me.clone();
 			let id = ui.push_id(im_str!("texture"));
+			let mut changed = false;
 
-			if !texture_list.is_empty() {
-				let mut current = 0;
-				let mut items = Vec::<imgui::ImString>::with_capacity(texture_list.len());
-				let list_vec = texture_list.iter().collect::<Vec<_>>();
-				for (i, (key, texture)) in list_vec.iter().enumerate() {
-					if new_me == **texture {
-						current = i as i32;
-					}
-					items.push(im_str!("{}", key).into());
-				}
-
-				ui.combo(im_str!("texture"), &mut current, items.iter().map(std::ops::Deref::deref).collect::<Vec<_>>().as_slice(), 10);
-				new_me = list_vec[current as usize].1.clone();
-			}
+			new_me.control().data(texture_data).state(&mut *state).label(im_str!("texture")).changed(&mut changed).build();
 
-			if *me != new_me {
+			if changed {
 				lazy.insert(entity, new_me);
 			}
 			id.pop(ui);
 		});
 	}
 
-	fn can_add((_, texture_list, _): &mut Self::SystemData, _: Entity) -> bool {
+	fn can_add((_, (texture_list, _), _): &mut Self::SystemData, _: Entity) -> bool {
+		if texture_list.is_empty() {
+			texture_list.request();
+		}
 		!texture_list.is_empty()
 	}
 
-	fn add((_, texture_list, lazy): &mut Self::SystemData, entity: Entity) {
+	fn add((_, (texture_list, _), lazy): &mut Self::SystemData, entity: Entity) {
 		// idk if I should insert UiTransform since idk if anything but the ui uses TextureHandle component
 		lazy.insert(entity, texture_list.values().nth(0).unwrap_or_else(f!()).clone());
 	}
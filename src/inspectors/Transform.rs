@@ -1,54 +1,380 @@
-use crate::prelude::*;
+use crate::{prelude::*, SnapSettings, SnapTo};
+use amethyst::core::{
+	math::{Matrix3, Matrix4, Quaternion, Rotation3, Translation3, Unit, UnitQuaternion, U3},
+	Parent,
+};
 
-#[derive(Default, Clone)]
-pub struct TransformInspectorData {
-	radians: bool,
+/// How the rotation of a `Transform` is edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationMode {
+	/// A single angle around Z, only for entities that aren't rotated around X or Y
+	Flat,
+	/// Euler angles in degrees, applied in the picked order
+	Euler(EulerOrder),
+	/// Raw `i j k w` components, normalized after every edit
+	Quaternion,
+}
+
+/// Order the axis rotations are applied in, `XYZ` rotates around X first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+	XYZ,
+	XZY,
+	YXZ,
+	YZX,
+	ZXY,
+	ZYX,
+}
+
+impl EulerOrder {
+	const ALL: [EulerOrder; 6] = [EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ, EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX];
+
+	fn name(self) -> &'static imgui::ImStr {
+		match self {
+			EulerOrder::XYZ => im_str!("XYZ"),
+			EulerOrder::XZY => im_str!("XZY"),
+			EulerOrder::YXZ => im_str!("YXZ"),
+			EulerOrder::YZX => im_str!("YZX"),
+			EulerOrder::ZXY => im_str!("ZXY"),
+			EulerOrder::ZYX => im_str!("ZYX"),
+		}
+	}
+
+	fn axes(self) -> [usize; 3] {
+		match self {
+			EulerOrder::XYZ => [0, 1, 2],
+			EulerOrder::XZY => [0, 2, 1],
+			EulerOrder::YXZ => [1, 0, 2],
+			EulerOrder::YZX => [1, 2, 0],
+			EulerOrder::ZXY => [2, 0, 1],
+			EulerOrder::ZYX => [2, 1, 0],
+		}
+	}
+
+	/// Angles in radians around X, Y and Z
+	pub fn to_rotation(self, angles: Vector3<f32>) -> UnitQuaternion<f32> {
+		let [i, j, k] = self.axes();
+		let around = |axis: usize| UnitQuaternion::from_axis_angle(&Unit::new_unchecked(Vector3::ith(axis, 1.)), angles[axis]);
+		around(k) * around(j) * around(i)
+	}
+
+	/// Angles in radians around X, Y and Z, the middle rotation is kept within `-PI/2 ..= PI/2`
+	pub fn from_rotation(self, rotation: &UnitQuaternion<f32>) -> Vector3<f32> {
+		let [i, j, k] = self.axes();
+		let m = rotation.to_rotation_matrix().into_inner();
+		// the sign flips for the orders that aren't a cyclic permutation of XYZ
+		let s = if (j + 3 - i) % 3 == 1 { 1. } else { -1. };
+		let mut angles = Vector3::zeros();
+		angles[j] = (-s * m[(k, i)]).max(-1.).min(1.).asin();
+		angles[i] = (s * m[(k, j)]).atan2(m[(k, k)]);
+		angles[k] = (s * m[(j, i)]).atan2(m[(i, i)]);
+		angles
+	}
+}
+
+/// Per-entity state of the `Transform` inspector
+#[derive(Debug, Clone, Default)]
+pub struct TransformState {
+	/// Whether the scale lock is on
+	pub proportional: bool,
+	/// Whether the fields show and edit the transform relative to the world instead of the parent
+	pub world: bool,
+	/// Picked from the dropdown, `None` picks flat or euler depending on the rotation
+	pub mode: Option<RotationMode>,
+	/// Angles last shown for the rotation, so they don't jump between equivalent triples while dragging
+	euler: Option<(UnitQuaternion<f32>, Vector3<f32>)>,
+}
+
+/// Splits an affine matrix without shear back into a transform
+///
+/// The scale takes the signs of `signs` where the matrix allows it, so a mirrored transform comes back mirrored on the
+/// same axes instead of on X with a half turn added to the rotation.
+pub(crate) fn decompose(matrix: &Matrix4<f32>, signs: &Vector3<f32>) -> Transform {
+	let linear: Matrix3<f32> = matrix.fixed_slice::<U3, U3>(0, 0).into_owned();
+	let mut scale = Vector3::new(linear.column(0).norm(), linear.column(1).norm(), linear.column(2).norm());
+	let mut flips = signs.map(|x| if x < 0. { -1. } else { 1. });
+	// a mirrored matrix can't be a rotation, put the flip in the scale instead
+	if (linear.determinant() < 0.) != (flips.product() < 0.) {
+		flips.x = -flips.x;
+	}
+	scale.component_mul_assign(&flips);
+	let mut rotation = linear;
+	for i in 0 .. 3 {
+		if scale[i].abs() > std::f32::EPSILON {
+			rotation.column_mut(i).unscale_mut(scale[i]);
+		}
+	}
+	let rotation = UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(rotation));
+	Transform::new(Translation3::new(matrix[(0, 3)], matrix[(1, 3)], matrix[(2, 3)]), rotation, scale)
+}
+
+/// Whether the matrix scales every direction alike
+///
+/// Only then does it keep a transform free of shear both ways: a parent that scales unevenly shears a rotated child,
+/// and moving a world space edit back under it shears the local transform, which `decompose` would silently drop.
+pub(crate) fn is_uniform(matrix: &Matrix4<f32>) -> bool {
+	let linear: Matrix3<f32> = matrix.fixed_slice::<U3, U3>(0, 0).into_owned();
+	let norms = [linear.column(0).norm(), linear.column(1).norm(), linear.column(2).norm()];
+	let tolerance = 1e-4 * norms.iter().cloned().fold(0., f32::max);
+	(0 .. 3).all(|i| (norms[i] - norms[0]).abs() <= tolerance)
+		&& [(0, 1), (0, 2), (1, 2)].iter().all(|&(i, j)| linear.column(i).dot(&linear.column(j)).abs() <= tolerance * norms[0])
+}
+
+/// Global matrix of the entity's parent, identity if it has none
+pub(crate) fn parent_matrix(transforms: &ReadStorage<'_, Transform>, parents: &ReadStorage<'_, Parent>, entity: Entity) -> Matrix4<f32> {
+	parents.get(entity).and_then(|x| transforms.get(x.entity)).map(|x| *x.global_matrix()).unwrap_or_else(Matrix4::identity)
+}
+
+fn is_flat(rotation: &UnitQuaternion<f32>) -> bool {
+	let q = rotation.quaternion();
+	q.i.abs() < std::f32::EPSILON && q.j.abs() < std::f32::EPSILON
 }
 
-// TODO: realfield thing
 impl<'a> Inspect<'a> for Transform {
 	type SystemData = (
 		ReadStorage<'a, Self>,
+		ReadStorage<'a, Parent>,
 		Read<'a, LazyUpdate>,
-		Write<'a, TransformInspectorData>,
 	);
+	type State = TransformState;
+
+	fn copy(&self) -> Option<Self> { Some(self.clone()) }
 
 	fn can_add(_: &mut Self::SystemData, _: ::amethyst::ecs::Entity) -> bool { true }
-	fn inspect((storage, lazy, data): &mut Self::SystemData, entity: Entity) {
+	fn inspect((storage, parents, lazy): &mut Self::SystemData, state: &mut Self::State, entity: Entity) {
 		amethyst_imgui::with(|ui| {
 			let me = if let Some(x) = storage.get(entity) { x } else { return; };
-			let mut new_me = me.clone();
 			let mut changed = false;
 			let id = ui.push_id(im_str!("Transform"));
 
-			new_me.translation_mut().control().null_to(0.).speed(0.05).label(im_str!("translation")).changed(&mut changed).build();
+			// the global matrix lags a frame behind edits, so the world transform is rebuilt from the parent's and the local one
+			let parent = parent_matrix(storage, parents, entity);
+			if ui.checkbox(im_str!("world space"), &mut state.world) {
+				state.euler = None;
+			}
+			let world = state.world && is_uniform(&parent);
+			if state.world && !world {
+				ui.text_colored([1., 0.6, 0.2, 1.], im_str!("a parent scales unevenly, world space would shear: editing in local space"));
+			}
+			let mut new_me = if world { decompose(&(parent * me.matrix()), me.scale()) } else { me.clone() };
+
+			new_me.translation_mut().control().null_to(0.).speed(0.05).snap().label(im_str!("translation")).changed(&mut changed).build();
+
+			let mode = state.mode.unwrap_or_else(|| if is_flat(new_me.rotation()) { RotationMode::Flat } else { RotationMode::Euler(EulerOrder::XYZ) });
+			match mode {
+				RotationMode::Flat => {
+					let mut rotation = new_me.rotation().euler_angles().2;
+					let mut flat_changed = false;
+					let control = rotation.control().angle().null_to(0.).label(im_str!("rotation")).changed(&mut flat_changed);
+					match SnapSettings::step(ui, SnapTo::Rotation) {
+						Some(step) => control.snap(step.to_radians() as f32).build(),
+						None => control.build(),
+					}
+					if flat_changed {
+						new_me.set_rotation_2d(rotation);
+						changed = true;
+					}
+				},
+				RotationMode::Euler(order) => {
+					let rotation = *new_me.rotation();
+					let mut angles = match state.euler {
+						Some((cached, angles)) if cached.angle_to(&rotation) < 1e-5 => angles,
+						_ => order.from_rotation(&rotation).map(|x| x.to_degrees()),
+					};
+					let mut euler_changed = false;
+					angles.control().null_to(0.).speed(0.25).snap_to(SnapTo::Rotation).label(im_str!("rotation")).changed(&mut euler_changed).build();
+					if euler_changed {
+						new_me.set_rotation(order.to_rotation(angles.map(|x| x.to_radians())));
+						changed = true;
+					}
+					state.euler = Some((*new_me.rotation(), angles));
+				},
+				RotationMode::Quaternion => {
+					let mut v = new_me.rotation().quaternion().coords;
+					let mut raw_changed = false;
+					v.control().null_to(0.).speed(0.0025).label(im_str!("rotation")).changed(&mut raw_changed).build();
+					if raw_changed {
+						if let Some(rotation) = UnitQuaternion::try_new(Quaternion::from(v), std::f32::EPSILON) {
+							new_me.set_rotation(rotation);
+							changed = true;
+						}
+					}
+				},
+			}
 
-			if data.radians {
-				let mut rotation = new_me.rotation().euler_angles().2;
-				rotation.control().null_to(0.).speed(0.25f32.to_radians()).label(im_str!("rotation")).changed(&mut changed).build();
-				new_me.set_rotation_2d(rotation);
-			} else {
-				let mut rotation = new_me.rotation().euler_angles().2.to_degrees();
-				if rotation == -180. {
-					rotation = 180.;
+			// auto, flat, quaternion, then one entry per euler order
+			let mut current = match state.mode {
+				None => 0,
+				Some(RotationMode::Flat) => 1,
+				Some(RotationMode::Quaternion) => 2,
+				Some(RotationMode::Euler(order)) => 3 + EulerOrder::ALL.iter().position(|&x| x == order).unwrap_or(0),
+			};
+			let mut items = vec![im_str!("auto"), im_str!("2d"), im_str!("quaternion")];
+			items.extend(EulerOrder::ALL.iter().map(|x| x.name()));
+			let token = ui.push_item_width(ui.window_size()[0] * 0.3);
+			if imgui::ComboBox::new(im_str!("rotation mode")).build_simple_string(ui, &mut current, items.as_slice()) {
+				state.mode = match current {
+					0 => None,
+					1 => Some(RotationMode::Flat),
+					2 => Some(RotationMode::Quaternion),
+					x => Some(RotationMode::Euler(EulerOrder::ALL[x - 3])),
+				};
+				state.euler = None;
+			}
+			drop(token);
+
+			new_me.scale_mut().control().null_to(1.).speed(0.01).snap_to(SnapTo::Scale).proportional().state(&mut state.proportional).label(im_str!("scale")).changed(&mut changed).build();
+
+			let scale = *new_me.scale();
+			let mut uniform = scale.iter().sum::<f32>() / 3.;
+			if ui.drag_float(im_str!("uniform scale"), &mut uniform).speed(0.01).build() {
+				let mean = scale.iter().sum::<f32>() / 3.;
+				if let Some(step) = SnapSettings::step(ui, SnapTo::Scale) {
+					// keyed by the stored transform, the scale field's address is already the key of its x
+					uniform = crate::controls::snap::round(me as *const Transform as usize, f64::from(mean), f64::from(uniform), step) as f32;
 				}
-				rotation.control().null_to(0.).speed(0.25).label(im_str!("rotation")).changed(&mut changed).build();
-				new_me.set_rotation_2d(rotation.to_radians());
+				*new_me.scale_mut() = if mean.abs() > std::f32::EPSILON { scale * (uniform / mean) } else { Vector3::repeat(uniform) };
+				changed = true;
 			}
-			ui.same_line(0.);
-			ui.checkbox(im_str!("radians"), &mut data.radians);
 
-			new_me.scale_mut().control().null_to(1.).speed(0.01).label(im_str!("scale")).changed(&mut changed).build();
+			ui.tree_node(im_str!("global matrix")).build(|| {
+				let global = me.global_matrix();
+				for row in 0 .. 4 {
+					ui.text(im_str!("{:>9.3} {:>9.3} {:>9.3} {:>9.3}", global[(row, 0)], global[(row, 1)], global[(row, 2)], global[(row, 3)]));
+				}
+			});
 
 			if changed {
-				lazy.insert(entity, new_me);
+				if world {
+					match parent.try_inverse() {
+						Some(inverse) => lazy.insert(entity, decompose(&(inverse * new_me.matrix()), me.scale())),
+						None => amethyst::log::warn!("can't edit in world space, the parent's matrix isn't invertible"),
+					}
+				} else {
+					lazy.insert(entity, new_me);
+				}
 			}
 
 			id.pop(ui);
 		});
 	}
 
-	fn add((_storage, lazy, _): &mut Self::SystemData, entity: Entity) {
+	fn add((_storage, _parents, lazy): &mut Self::SystemData, entity: Entity) {
 		lazy.insert(entity, Self::default());
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{decompose, is_uniform, EulerOrder};
+	use amethyst::core::{
+		math::{Matrix4, Translation3, UnitQuaternion, Vector3},
+		Transform,
+	};
+
+	fn close(a: &Vector3<f32>, b: &Vector3<f32>) -> bool {
+		(a - b).amax() < 1e-4
+	}
+
+	#[test]
+	fn euler_round_trip() {
+		for &order in EulerOrder::ALL.iter() {
+			// the outer angles go all the way round, the middle one only comes back within a quarter turn
+			let [i, j, k] = order.axes();
+			let mut wide = Vector3::zeros();
+			wide[i] = 3.;
+			wide[j] = -1.2;
+			wide[k] = -2.8;
+			for angles in [Vector3::new(0.3, -0.7, 1.2), Vector3::new(-1.4, 1.1, 0.4), wide, Vector3::zeros()].iter() {
+				let rotation = order.to_rotation(*angles);
+				let back = order.from_rotation(&rotation);
+				assert!(close(&back, angles), "{:?} gave {:?} back for {:?}", order, back, angles);
+				assert!(order.to_rotation(back).angle_to(&rotation) < 1e-4, "{:?} {:?}", order, angles);
+			}
+		}
+	}
+
+	#[test]
+	fn euler_applies_in_order() {
+		let angles = Vector3::new(0.3, -0.7, 1.2);
+		let x = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), angles.x);
+		let y = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), angles.y);
+		let z = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), angles.z);
+		let expected = [
+			(EulerOrder::XYZ, z * y * x),
+			(EulerOrder::XZY, y * z * x),
+			(EulerOrder::YXZ, z * x * y),
+			(EulerOrder::YZX, x * z * y),
+			(EulerOrder::ZXY, y * x * z),
+			(EulerOrder::ZYX, x * y * z),
+		];
+		for (order, rotation) in expected.iter() {
+			assert!(order.to_rotation(angles).angle_to(rotation) < 1e-5, "{:?}", order);
+		}
+	}
+
+	#[test]
+	fn euler_gimbal_lock() {
+		// the middle angle at a right angle leaves the outer two ambiguous, only the rotation has to match
+		for &order in EulerOrder::ALL.iter() {
+			let [_, j, _] = order.axes();
+			let mut angles = Vector3::new(0.4, 0.4, 0.4);
+			angles[j] = std::f32::consts::FRAC_PI_2;
+			let rotation = order.to_rotation(angles);
+			let back = order.from_rotation(&rotation);
+			assert!(order.to_rotation(back).angle_to(&rotation) < 1e-3, "{:?} gave {:?} back", order, back);
+		}
+	}
+
+	fn transform(scale: Vector3<f32>) -> Transform {
+		let rotation = UnitQuaternion::from_euler_angles(0.3, -0.7, 1.2);
+		Transform::new(Translation3::new(1., -2., 3.), rotation, scale)
+	}
+
+	fn same_matrix(a: &Matrix4<f32>, b: &Matrix4<f32>) -> bool {
+		(a - b).amax() < 1e-4
+	}
+
+	#[test]
+	fn decompose_keeps_hinted_signs() {
+		let scales = [Vector3::new(2., 3., 0.5), Vector3::new(-2., 3., 0.5), Vector3::new(2., -3., -0.5), Vector3::new(-1., -1., -1.)];
+		for scale in scales.iter() {
+			let matrix = transform(*scale).matrix();
+			let back = decompose(&matrix, scale);
+			assert!(close(back.scale(), scale), "{:?} came back as {:?}", scale, back.scale());
+			assert!(same_matrix(&back.matrix(), &matrix), "{:?}", scale);
+		}
+	}
+
+	#[test]
+	fn decompose_mirrors_on_x_without_a_hint() {
+		// a single mirror has to end up in the scale, an even number of them can be a rotation instead
+		let matrix = transform(Vector3::new(2., -3., 0.5)).matrix();
+		let back = decompose(&matrix, &Vector3::new(1., 1., 1.));
+		assert!(close(back.scale(), &Vector3::new(-2., 3., 0.5)), "{:?}", back.scale());
+		assert!(same_matrix(&back.matrix(), &matrix));
+
+		let matrix = transform(Vector3::new(-2., -3., 0.5)).matrix();
+		let back = decompose(&matrix, &Vector3::new(1., 1., 1.));
+		assert!(close(back.scale(), &Vector3::new(2., 3., 0.5)), "{:?}", back.scale());
+		assert!(same_matrix(&back.matrix(), &matrix));
+	}
+
+	#[test]
+	fn uniform_parents() {
+		assert!(is_uniform(&Matrix4::identity()));
+		assert!(is_uniform(&transform(Vector3::new(2., 2., 2.)).matrix()));
+		assert!(is_uniform(&transform(Vector3::new(-2., 2., 2.)).matrix()));
+		assert!(!is_uniform(&transform(Vector3::new(1., 2., 1.)).matrix()));
+		assert!(!is_uniform(&transform(Vector3::new(-2., 2.1, 2.)).matrix()));
+	}
+
+	#[test]
+	fn uneven_parent_shears_rotated_child() {
+		let parent = Transform::new(Translation3::identity(), UnitQuaternion::identity(), Vector3::new(1., 3., 1.)).matrix();
+		let child = transform(Vector3::new(1., 1., 1.)).matrix();
+		assert!(!is_uniform(&(parent * child)));
+		let parent = transform(Vector3::new(3., 3., 3.)).matrix();
+		assert!(is_uniform(&(parent * child)));
+	}
+}
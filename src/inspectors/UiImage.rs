@@ -0,0 +1,160 @@
+use amethyst::{
+	assets::{AssetStorage, Handle},
+	core::math::{Vector2, Vector4},
+	ecs::prelude::*,
+	renderer::{SpriteRender, SpriteSheet, Texture},
+	ui::UiImage,
+};
+use amethyst_imgui::imgui;
+use crate::{Inspect, InspectControl, InspectControlBuilder};
+use imgui::im_str;
+
+const IMAGE_KINDS: [&str; 5] = ["texture", "partial texture", "solid colour", "nine slice", "sprite"];
+
+fn image_kind(image: &UiImage) -> usize {
+	match image {
+		UiImage::Texture(_) => 0,
+		UiImage::PartialTexture(..) => 1,
+		UiImage::SolidColor(_) => 2,
+		UiImage::NineSlice { .. } => 3,
+		UiImage::Sprite(_) => 4,
+	}
+}
+
+impl<'a> Inspect<'a> for UiImage {
+	type SystemData = (
+		ReadStorage<'a, Self>,
+		<&'a mut Handle<Texture> as InspectControl<'a, 'a>>::SystemData,
+		<&'a mut Handle<SpriteSheet> as InspectControl<'a, 'a>>::SystemData,
+		Read<'a, LazyUpdate>,
+	);
+	/// Search filters of the texture and sprite sheet pickers
+	type State = (imgui::ImString, imgui::ImString);
+
+	fn copy(&self) -> Option<Self> { Some(self.clone()) }
+
+	fn inspect((storage, texture_data, sheet_data, lazy): &mut Self::SystemData, (texture_search, sheet_search): &mut Self::State, entity: Entity) {
+		amethyst_imgui::with(|ui| {
+			let me = if let Some(x) = storage.get(entity) { x } else { return; };
+			let mut new_me = me.clone();
+			let id = ui.push_id(im_str!("ui_image"));
+			let mut changed = false;
+
+			// switching keeps whatever texture the image already had
+			let mut kind = image_kind(me);
+			let items = IMAGE_KINDS.iter().map(|x| im_str!("{}", x)).collect::<Vec<_>>();
+			if imgui::ComboBox::new(im_str!("kind")).build_simple_string(ui, &mut kind, items.iter().map(std::ops::Deref::deref).collect::<Vec<_>>().as_slice()) {
+				let texture = match me {
+					UiImage::Texture(tex) | UiImage::PartialTexture(tex, ..) | UiImage::NineSlice { tex, .. } => Some(tex.clone()),
+					_ => texture_data.0.values().nth(0).cloned(),
+				};
+				let sheet = match me {
+					UiImage::Sprite(sprite) => Some(sprite.sprite_sheet.clone()),
+					_ => sheet_data.0.values().nth(0).cloned(),
+				};
+				let switched = match (kind, texture, sheet) {
+					(0, Some(tex), _) => Some(UiImage::Texture(tex)),
+					(1, Some(tex), _) => Some(UiImage::PartialTexture(tex, 0., 1., 0., 1.)),
+					(2, ..) => Some(UiImage::SolidColor([1., 1., 1., 1.])),
+					(3, Some(tex), _) => Some(UiImage::NineSlice {
+						x_start: 0,
+						y_start: 0,
+						width: 0,
+						height: 0,
+						left_dist: 0,
+						right_dist: 0,
+						top_dist: 0,
+						bottom_dist: 0,
+						tex,
+						texture_dimensions: [0, 0],
+					}),
+					(4, _, Some(sprite_sheet)) => Some(UiImage::Sprite(SpriteRender { sprite_sheet, sprite_number: 0 })),
+					_ => None,
+				};
+				match switched {
+					Some(image) => {
+						new_me = image;
+						changed = true;
+					},
+					None => {
+						texture_data.0.request();
+						sheet_data.0.request();
+					},
+				}
+			}
+
+			match &mut new_me {
+				UiImage::Texture(tex) => {
+					tex.control().data(texture_data).state(texture_search).label(im_str!("texture")).changed(&mut changed).build();
+				},
+				UiImage::PartialTexture(tex, left, right, bottom, top) => {
+					tex.control().data(texture_data).state(texture_search).label(im_str!("texture")).changed(&mut changed).build();
+					let mut min = Vector2::new(*left, *bottom);
+					let mut max = Vector2::new(*right, *top);
+					min.control().null_to(0.).speed(0.001).label(im_str!("uv min")).changed(&mut changed).build();
+					max.control().null_to(1.).speed(0.001).label(im_str!("uv max")).changed(&mut changed).build();
+					let clamp = |x: f32| x.max(0.).min(1.);
+					*left = clamp(min.x);
+					*bottom = clamp(min.y);
+					*right = clamp(max.x);
+					*top = clamp(max.y);
+				},
+				UiImage::SolidColor(color) => {
+					color.control().color().label(im_str!("colour")).changed(&mut changed).build();
+				},
+				UiImage::NineSlice { x_start, y_start, width, height, left_dist, right_dist, top_dist, bottom_dist, tex, texture_dimensions } => {
+					tex.control().data(texture_data).state(texture_search).label(im_str!("texture")).changed(&mut changed).build();
+					let mut start = Vector2::new(*x_start, *y_start);
+					let mut size = Vector2::new(*width, *height);
+					let mut margins = Vector4::new(*left_dist, *right_dist, *top_dist, *bottom_dist);
+					let mut dimensions = Vector2::new(texture_dimensions[0], texture_dimensions[1]);
+					start.control().label(im_str!("start")).changed(&mut changed).build();
+					size.control().label(im_str!("size")).changed(&mut changed).build();
+					margins.control().label(im_str!("left right top bottom")).changed(&mut changed).build();
+					dimensions.control().label(im_str!("texture size")).changed(&mut changed).build();
+					*x_start = start.x;
+					*y_start = start.y;
+					*width = size.x;
+					*height = size.y;
+					*left_dist = margins[0];
+					*right_dist = margins[1];
+					*top_dist = margins[2];
+					*bottom_dist = margins[3];
+					*texture_dimensions = [dimensions.x, dimensions.y];
+				},
+				UiImage::Sprite(sprite) => {
+					let sheet = sprite.sprite_sheet.clone();
+					sprite.sprite_sheet.control().data(sheet_data).state(sheet_search).label(im_str!("sprite sheet")).changed(&mut changed).build();
+					if sprite.sprite_sheet != sheet {
+						sprite.sprite_number = 0;
+					}
+
+					let sprites: &AssetStorage<SpriteSheet> = &sheet_data.1;
+					match sprites.get(&sprite.sprite_sheet) {
+						Some(sheet) if sheet.sprites.is_empty() => ui.text_disabled(im_str!("# sprite: sheet has no sprites")),
+						Some(sheet) => {
+							let mut sprite_number = sprite.sprite_number as i32;
+							changed = imgui::Slider::new(
+								im_str!("# sprite"),
+								0 ..= sheet.sprites.len() as i32 - 1,
+							).build(ui, &mut sprite_number) || changed;
+							sprite.sprite_number = sprite_number as usize;
+						},
+						None => ui.text_disabled(im_str!("# sprite: sheet is loading")),
+					}
+				},
+			}
+
+			if changed {
+				lazy.insert(entity, new_me);
+			}
+			id.pop(ui);
+		});
+	}
+
+	fn can_add(_: &mut Self::SystemData, _: Entity) -> bool { true }
+
+	fn add((.., lazy): &mut Self::SystemData, entity: Entity) {
+		lazy.insert(entity, UiImage::SolidColor([1., 1., 1., 1.]));
+	}
+}
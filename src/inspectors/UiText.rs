@@ -1,27 +1,29 @@
 use amethyst::{
 	ecs::prelude::*,
-	assets::AssetStorage,
-	ui::UiTransform,
+	ui::{FontAsset, FontHandle, UiTransform},
 };
 use amethyst_imgui::imgui;
-use crate::Inspect;
+use crate::{controls::assets::AssetList, Inspect, InspectControl, InspectControlBuilder};
 use imgui::im_str;
 
 /// Add this as a resource and insert your handles into it to get a dropdown for FontHandle selection
-pub type FontList = std::collections::HashMap<String, amethyst::ui::FontHandle>;
+pub type FontList = AssetList<FontAsset>;
 
 impl<'a> Inspect<'a> for amethyst::ui::UiText {
 	type SystemData = (
 		ReadStorage<'a, Self>,
 		ReadStorage<'a, UiTransform>,
 		ReadExpect<'a, amethyst::assets::Loader>,
-		ReadExpect<'a, AssetStorage<amethyst::ui::FontAsset>>,
-		Read<'a, FontList>,
+		<&'a mut FontHandle as InspectControl<'a, 'a>>::SystemData,
 		Read<'a, LazyUpdate>,
 	);
+	/// Search filter of the asset picker
+	type State = imgui::ImString;
+
+	fn copy(&self) -> Option<Self> { Some(self.clone()) }
 
 	fn can_add(_: &mut Self::SystemData, _: ::amethyst::ecs::Entity) -> bool { true }
-	fn inspect((storage, _, _, _, font_list, lazy): &mut Self::SystemData, entity: Entity) {
+	fn inspect((storage, _, _, font_data, lazy): &mut Self::SystemData, state: &mut Self::State, entity: Entity) {
 		amethyst_imgui::with(|ui| {
 			let me = if let Some(x) = storage.get(entity) { x } else { return; };
 			let mut new_me = me.clone();
@@ -36,29 +38,13 @@ impl<'a> Inspect<'a> for amethyst::ui::UiText {
 				new_me.text = buf.to_str().to_owned();
 			}
 
-
-			if !font_list.is_empty() {
-				let mut current = 0;
-				let mut items = Vec::<imgui::ImString>::with_capacity(9);
-				let list_vec = font_list.iter().collect::<Vec<_>>();
-				for (i, (key, font)) in list_vec.iter().enumerate() {
-					if me.font == **font {
-						current = i;
-					}
-					items.push(im_str!("{}", key));
-				}
-
-				imgui::ComboBox::new(im_str!("font")).build_simple_string(ui, &mut current, items.iter().map(std::ops::Deref::deref).collect::<Vec<_>>().as_slice());
-				new_me.font = list_vec[current as usize].1.clone();
-			}
+			new_me.font.control().data(font_data).state(&mut *state).label(im_str!("font")).changed(&mut changed).build();
 
 			changed = ui.drag_float(im_str!("font size"), &mut new_me.font_size)
 				.speed(0.5)
 				.build() || changed;
 
-			changed = ui.drag_float4(im_str!("colour"), &mut new_me.color)
-				.speed(0.005)
-				.build() || changed;
+			new_me.color.control().color().null_to([1., 1., 1., 1.]).label(im_str!("colour")).changed(&mut changed).build();
 
 			changed = ui.checkbox(im_str!("password"), &mut new_me.password) || changed;
 
@@ -117,7 +103,7 @@ impl<'a> Inspect<'a> for amethyst::ui::UiText {
 		});
 	}
 
-	fn add((_storage, transforms, loader, fonts, font_list, lazy): &mut Self::SystemData, entity: Entity) {
+	fn add((_storage, transforms, loader, (font_list, fonts), lazy): &mut Self::SystemData, entity: Entity) {
 		let font = if font_list.is_empty() { amethyst::ui::get_default_font(&loader, &fonts) } else { font_list.values().nth(0).unwrap_or_else(f!()).clone() };
 		if !transforms.contains(entity) {
 			lazy.insert(entity, UiTransform::new(String::default(), amethyst::ui::Anchor::Middle, amethyst::ui::Anchor::Middle, 0., 0., 0., 100., 100.));
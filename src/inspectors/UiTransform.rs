@@ -1,16 +1,20 @@
 use amethyst::{
 	ecs::prelude::*,
-	ui::UiTransform,
+	ui::{Stretch, UiTransform},
+	window::ScreenDimensions,
 };
 use amethyst_imgui::imgui::{self, im_str};
 use crate::Inspect;
 use crate::prelude::*;
 
 impl<'a> Inspect<'a> for UiTransform {
-	type SystemData = (ReadStorage<'a, Self>, Read<'a, LazyUpdate>);
+	type SystemData = (ReadStorage<'a, Self>, ReadExpect<'a, ScreenDimensions>, Read<'a, LazyUpdate>);
+	type State = ();
+
+	fn copy(&self) -> Option<Self> { Some(self.clone()) }
 
 	fn can_add(_: &mut Self::SystemData, _: ::amethyst::ecs::Entity) -> bool { true }
-	fn inspect((storage, lazy): &mut Self::SystemData, entity: Entity) {
+	fn inspect((storage, screen, lazy): &mut Self::SystemData, _: &mut Self::State, entity: Entity) {
 		amethyst_imgui::with(|ui| {
 			use amethyst::ui::ScaleMode;
 
@@ -19,16 +23,19 @@ impl<'a> Inspect<'a> for UiTransform {
 			let mut changed = false;
 			let id = ui.push_id(im_str!("ui_transform"));
 
+			new_me.id.control().label(im_str!("id")).changed(&mut changed).build();
+
 			{
 				let mut v: Vector3<f32> = Vector3::new(me.local_x, me.local_y, me.local_z);
 
-				v
+				let control = v
 					.control()
 					.null_to(0.)
 					.speed(if me.scale_mode == ScaleMode::Pixel { 1. } else { 0.001 })
 					.label(im_str!("translation"))
-					.changed(&mut changed)
-					.build();
+					.changed(&mut changed);
+				// the grid is in pixels, a step of one in percent mode is the whole parent
+				if me.scale_mode == ScaleMode::Pixel { control.snap().build() } else { control.build() }
 
 				new_me.local_x = v[0];
 				new_me.local_y = v[1];
@@ -38,13 +45,13 @@ impl<'a> Inspect<'a> for UiTransform {
 			{
 				let mut v: Vector2<f32> = Vector2::new(me.width, me.height);
 
-				v
+				let control = v
 					.control()
 					.null_to(if me.scale_mode == ScaleMode::Pixel { 100. } else { 1. })
 					.speed(if me.scale_mode == ScaleMode::Pixel { 1. } else { 0.001 })
 					.label(im_str!("size"))
-					.changed(&mut changed)
-					.build();
+					.changed(&mut changed);
+				if me.scale_mode == ScaleMode::Pixel { control.snap().build() } else { control.build() }
 
 				new_me.width = v[0];
 				new_me.height = v[1];
@@ -89,6 +96,65 @@ impl<'a> Inspect<'a> for UiTransform {
 				new_me.scale_mode = modes[current as usize].clone();
 			}
 
+			{
+				let mut current = match me.stretch {
+					Stretch::NoStretch => 0,
+					Stretch::X { .. } => 1,
+					Stretch::Y { .. } => 2,
+					Stretch::XY { .. } => 3,
+				};
+				let items = [im_str!("none"), im_str!("x"), im_str!("y"), im_str!("xy")];
+				if imgui::ComboBox::new(im_str!("stretch")).build_simple_string(ui, &mut current, &items) {
+					// margins carry over between the variants that have them
+					let (x_margin, y_margin, keep_aspect_ratio) = match me.stretch {
+						Stretch::NoStretch => (0., 0., false),
+						Stretch::X { x_margin } => (x_margin, 0., false),
+						Stretch::Y { y_margin } => (0., y_margin, false),
+						Stretch::XY { x_margin, y_margin, keep_aspect_ratio } => (x_margin, y_margin, keep_aspect_ratio),
+					};
+					new_me.stretch = match current {
+						1 => Stretch::X { x_margin },
+						2 => Stretch::Y { y_margin },
+						3 => Stretch::XY { x_margin, y_margin, keep_aspect_ratio },
+						_ => Stretch::NoStretch,
+					};
+					changed = true;
+				}
+
+				match &mut new_me.stretch {
+					Stretch::NoStretch => {},
+					Stretch::X { x_margin } => x_margin.control().null_to(0.).label(im_str!("x margin")).changed(&mut changed).build(),
+					Stretch::Y { y_margin } => y_margin.control().null_to(0.).label(im_str!("y margin")).changed(&mut changed).build(),
+					Stretch::XY { x_margin, y_margin, keep_aspect_ratio } => {
+						let mut margin = Vector2::new(*x_margin, *y_margin);
+						margin.control().null_to(0.).label(im_str!("margin")).changed(&mut changed).build();
+						*x_margin = margin.x;
+						*y_margin = margin.y;
+						keep_aspect_ratio.control().label(im_str!("keep aspect ratio")).changed(&mut changed).build();
+					},
+				}
+			}
+
+			new_me.opaque.control().label(im_str!("opaque")).changed(&mut changed).build();
+			new_me.transparent_target.control().label(im_str!("transparent target")).changed(&mut changed).build();
+
+			// computed by the UI system from everything above, so only shown
+			ui.tree_node(im_str!("pixel rect")).default_open(true).build(|| {
+				ui.text(im_str!("center {:.1}, {:.1}  size {:.1} x {:.1}", me.pixel_x(), me.pixel_y(), me.pixel_width(), me.pixel_height()));
+
+				// the screen scaled down to the window's width with the rect drawn where it is, UI's Y goes up
+				let (screen_width, screen_height) = (screen.width().max(1.), screen.height().max(1.));
+				let width = ui.content_region_avail()[0].max(1.);
+				let scale = width / screen_width;
+				let origin = ui.cursor_screen_pos();
+				let draw_list = ui.get_window_draw_list();
+				draw_list.add_rect(origin, [origin[0] + width, origin[1] + screen_height * scale], [0.5, 0.5, 0.5, 1.]).build();
+				let left = origin[0] + (me.pixel_x() - me.pixel_width() / 2.) * scale;
+				let top = origin[1] + (screen_height - me.pixel_y() - me.pixel_height() / 2.) * scale;
+				draw_list.add_rect([left, top], [left + me.pixel_width() * scale, top + me.pixel_height() * scale], [1., 0.85, 0.2, 0.5]).filled(true).build();
+				ui.dummy([width, screen_height * scale]);
+			});
+
 			id.pop(ui);
 
 			if changed {
@@ -97,7 +163,7 @@ impl<'a> Inspect<'a> for UiTransform {
 		});
 	}
 
-	fn add((_, lazy): &mut Self::SystemData, entity: Entity) {
+	fn add((_, _, lazy): &mut Self::SystemData, entity: Entity) {
 		lazy.insert(entity, UiTransform::new(String::default(), amethyst::ui::Anchor::Middle, amethyst::ui::Anchor::Middle, 0., 0., 0., 100., 100.));
 	}
 }
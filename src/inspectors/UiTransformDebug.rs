@@ -1,19 +1,30 @@
 use amethyst::{
-	core::{transform::Transform, Named},
+	core::{transform::Transform, Named, ParentHierarchy},
 	ecs::prelude::*,
-	renderer::Rgba,
+	renderer::{debug_drawing::DebugLines, palette::Srgba, ActiveCamera, Camera},
 	ui::UiTransform,
+	window::ScreenDimensions,
 };
 use amethyst_imgui::imgui;
-use crate::Inspect;
+use crate::{
+	gizmo::find_camera,
+	outline::{draw_quad, ui_bounds},
+	Inspect,
+	InspectControl,
+	InspectControlBuilder,
+};
 use imgui::im_str;
 
+/// Draws the rect of its entity's `UiTransform` with `DebugLines`, needs `RenderDebugLines` in the render bundle
 #[derive(Clone, PartialEq)]
 pub struct UiTransformDebug {
-	camera: Entity,
-	color: Rgba,
-	children: bool,
-	always: bool,
+	/// Camera the rect is drawn in front of, the active one if `None`
+	pub camera: Option<Entity>,
+	pub color: Srgba,
+	/// Also draw the rects of every descendant
+	pub children: bool,
+	/// Draw even when the entity isn't selected in the inspector
+	pub always: bool,
 }
 impl Component for UiTransformDebug {
 	type Storage = amethyst::ecs::DenseVecStorage<Self>;
@@ -24,98 +35,84 @@ impl<'a> Inspect<'a> for UiTransformDebug {
 		ReadStorage<'a, Self>,
 		ReadStorage<'a, UiTransform>,
 		ReadStorage<'a, Transform>,
-		ReadStorage<'a, amethyst::core::Parent>,
-		ReadExpect<'a, amethyst::core::ParentHierarchy>,
-		ReadExpect<'a, amethyst::renderer::ScreenDimensions>,
+		ReadExpect<'a, ParentHierarchy>,
+		ReadExpect<'a, ScreenDimensions>,
 		ReadStorage<'a, Named>,
-		ReadStorage<'a, amethyst::renderer::Camera>,
+		ReadStorage<'a, Camera>,
+		Read<'a, ActiveCamera>,
+		Write<'a, DebugLines>,
 		Entities<'a>,
 		Read<'a, LazyUpdate>,
 	);
+	type State = ();
 
-	fn setup((storage, ui_transforms, transforms, _, _, dimensions, _, cameras, entities, lazy): &mut Self::SystemData, inspectee: Option<Entity>) {
-		for (debug, entity) in (&*storage, &*entities).join() {
-			if Some(entity) != inspectee && !debug.always { return; };
-			let transform = if let Some(x) = ui_transforms.get(entity) { x } else { return; };
-			let camera = if let Some(x) = cameras.get(debug.camera) { x } else { return; };
-			let camera_transform = if let Some(x) = transforms.get(debug.camera) { x } else { return; };
+	fn copy(&self) -> Option<Self> { Some(self.clone()) }
 
-			let matrix = camera_transform.matrix();
-			let x = transform.pixel_x();
-			let y = dimensions.height() - transform.pixel_y();
-			let z = transform.local_z;
-			let w = transform.width;
-			let h = transform.height;
+	fn setup((storage, ui_transforms, transforms, hierarchy, dimensions, _, cameras, active_camera, debug_lines, entities, _): &mut Self::SystemData, inspectee: Option<Entity>) {
+		for (debug, entity) in (&*storage, &*entities).join() {
+			if Some(entity) != inspectee && !debug.always { continue; };
+			let camera = match debug.camera {
+				Some(camera) => if let (Some(x), Some(y)) = (cameras.get(camera), transforms.get(camera)) { (x, y) } else { continue; },
+				None => if let Some(x) = find_camera(active_camera, cameras, transforms) { x } else { continue; },
+			};
 
-			let mut points = [
-				camera.position_from_screen([x - w * 0.5, y - h * 0.5].into(), &matrix, dimensions),
-				camera.position_from_screen([x + w * 0.5, y - h * 0.5].into(), &matrix, dimensions),
-				camera.position_from_screen([x + w * 0.5, y + h * 0.5].into(), &matrix, dimensions),
-				camera.position_from_screen([x - w * 0.5, y + h * 0.5].into(), &matrix, dimensions),
-			];
-			for p in points.iter_mut() {
-				p[2] = z;
+			let (red, green, blue, alpha) = debug.color.into_components();
+			let mut drawn = vec![entity];
+			if debug.children {
+				let mut i = 0;
+				while i < drawn.len() {
+					drawn.extend_from_slice(hierarchy.children(drawn[i]));
+					i += 1;
+				}
+			}
+			for ui_transform in drawn.into_iter().filter_map(|x| ui_transforms.get(x)) {
+				draw_quad(debug_lines, &ui_bounds(ui_transform, camera, dimensions), [red, green, blue, alpha]);
 			}
-
-			let color = debug.color;
-			lazy.exec(move |w| {
-				let mut lines = w.write_resource::<amethyst::renderer::DebugLines>();
-				lines.draw_line(points[0], points[1], color);
-				lines.draw_line(points[1], points[2], color);
-				lines.draw_line(points[2], points[3], color);
-				lines.draw_line(points[3], points[0], color);
-			});
 		}
 	}
 
-	fn inspect((storage, _, _, _, _, _, names, cameras, entities, lazy): &mut Self::SystemData, entity: Entity) {
+	fn inspect((storage, _, _, _, _, names, cameras, _, _, entities, lazy): &mut Self::SystemData, _: &mut Self::State, entity: Entity) {
 		amethyst_imgui::with(|ui| {
 			let me = if let Some(x) = storage.get(entity) { x } else { return; };
 			let mut new_me = me.clone();
-			ui.push_id(im_str!("ui_transform_debug"));
+			let id = ui.push_id(im_str!("ui_transform_debug"));
 
 			let camera_entities = (&*cameras, &*entities).join().map(|(_, e)| e).collect::<Vec<Entity>>();
-			if camera_entities.len() > 1 {
-				let mut current = 0;
-				let mut items = Vec::<imgui::ImString>::with_capacity(camera_entities.len());
-				for (i, &camera_entity) in camera_entities.iter().enumerate() {
-					if me.camera == camera_entity {
-						current = i as i32;
-					}
-
-					let label: String = if let Some(name) = names.get(camera_entity) {
-						name.name.to_string()
-					} else {
-						format!("Entity {}/{}", camera_entity.id(), camera_entity.gen().id())
-					};
-					items.push(im_str!("{}", label).into());
+			let mut current = 0;
+			let mut items = Vec::<imgui::ImString>::with_capacity(camera_entities.len() + 1);
+			items.push(im_str!("active camera").to_owned());
+			for (i, &camera_entity) in camera_entities.iter().enumerate() {
+				if me.camera == Some(camera_entity) {
+					current = i + 1;
 				}
 
-				ui.combo(im_str!("camera"), &mut current, items.iter().map(std::ops::Deref::deref).collect::<Vec<_>>().as_slice(), 10);
-				new_me.camera = camera_entities[current as usize];
+				let label: String = if let Some(name) = names.get(camera_entity) {
+					name.name.to_string()
+				} else {
+					format!("Entity {}/{}", camera_entity.id(), camera_entity.gen().id())
+				};
+				items.push(im_str!("{}", label));
+			}
+			if imgui::ComboBox::new(im_str!("camera")).build_simple_string(ui, &mut current, items.iter().map(std::ops::Deref::deref).collect::<Vec<_>>().as_slice()) {
+				new_me.camera = current.checked_sub(1).map(|i| camera_entities[i]);
 			}
 
-			let mut v: [f32; 4] = new_me.color.into();
-			ui.drag_float4(im_str!("colour"), &mut v)
-				.speed(0.005)
-				.build();
-			new_me.color = v.into();
+			new_me.color.control().label(im_str!("colour")).build();
 			ui.checkbox(im_str!("children"), &mut new_me.children);
 			ui.checkbox(im_str!("always"), &mut new_me.always);
 
 			if *me != new_me {
 				lazy.insert(entity, new_me);
 			}
-			ui.pop_id();
+			id.pop(ui);
 		});
 	}
 
-	fn can_add((_, ui_transforms, _, _, _, _, _, cameras, entities, _): &mut Self::SystemData, entity: Entity) -> bool {
-		(&*cameras, &*entities).join().nth(0).is_some() && ui_transforms.contains(entity)
+	fn can_add((_, ui_transforms, ..): &mut Self::SystemData, entity: Entity) -> bool {
+		ui_transforms.contains(entity)
 	}
 
-	fn add((_, _, _, _, _, _, _, cameras, entities, lazy): &mut Self::SystemData, entity: Entity) {
-		let (_, camera) = if let Some(x) = (&*cameras, &*entities).join().nth(0) { x } else { return; };
-		lazy.insert(entity, UiTransformDebug { camera, color: Rgba::red(), children: false, always: true });
+	fn add((.., lazy): &mut Self::SystemData, entity: Entity) {
+		lazy.insert(entity, UiTransformDebug { camera: None, color: Srgba::new(1., 0., 0., 1.), children: false, always: true });
 	}
 }
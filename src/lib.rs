@@ -34,11 +34,31 @@ use amethyst_imgui::imgui;
 
 mod prelude;
 mod hierarchy;
+mod gizmo;
+mod picker;
+mod outline;
+mod scanner;
 mod inspectors;
 mod controls;
 
 pub use hierarchy::InspectorHierarchy;
-pub use inspectors::{SpriteRender::SpriteList, UiText::FontList};
+pub use gizmo::{GizmoMode, TransformGizmo};
+pub use picker::EntityPicker;
+pub use outline::SelectionOutline;
+pub use scanner::AssetScanner;
+pub use controls::{
+	assets::AssetList,
+	flags::{Flags, FlagsControlBuilder},
+	misc::DurationUnit,
+	snap::{SnapModifier, SnapSettings, SnapTo},
+};
+pub use inspectors::{
+	SpriteRender::SpriteList,
+	TextureHandle::TextureList,
+	Transform::{EulerOrder, RotationMode, TransformState},
+	UiText::FontList,
+	UiTransformDebug::UiTransformDebug,
+};
 
 #[allow(unused_variables)]
 pub trait InspectControlBuilder<'control, 'resource: 'control, Value: InspectControl<'control, 'resource>>: Sized {
@@ -47,11 +67,17 @@ pub trait InspectControlBuilder<'control, 'resource: 'control, Value: InspectCon
 	fn label(self, label: &'control imgui::ImStr) -> Self { self }
 	fn build(self);
 	fn changed(self, changed: &'control mut bool) -> Self { self }
+	/// What "reset to default" in the control's context menu sets the value to, ignored if it's not the value's type
+	fn default_to(self, default: &'control dyn std::any::Any) -> Self { self }
+	/// Where the control keeps its UI state, without one the state only lasts the frame
+	fn state(self, state: &'control mut <Value as InspectControl<'control, 'resource>>::State) -> Self { self }
 }
 
 /// Implement this on your fields to be able to `#[derive(Inspect)]` on your struct
 pub trait InspectControl<'control, 'resource: 'control>: Sized {
 	type SystemData: SystemData<'resource>;
+	/// UI state remembered between frames, e.g. the unit a duration is shown in
+	type State: Default + Send + Sync + 'static;
 	type Builder: InspectControlBuilder<'control, 'resource, Self>;
 
 	fn control(self) -> Self::Builder {
@@ -59,10 +85,60 @@ pub trait InspectControl<'control, 'resource: 'control>: Sized {
 	}
 }
 
+/// Draw the control for `value` in whatever imgui window is being built, returns whether it was changed
+///
+/// For using controls outside of an inspector, e.g. in a game's own debug windows. An empty label hides it,
+/// `inspect_value!` makes one from the call site instead.
+pub fn inspect_value<T>(_ui: &imgui::Ui, label: &str, value: &mut T) -> bool
+where
+	for<'c> &'c mut T: InspectControl<'c, 'c, SystemData = ()>,
+{
+	let label = imgui::ImString::new(label);
+	let mut changed = false;
+	let control = value.control().changed(&mut changed);
+	if label.is_empty() { control.build() } else { control.label(&label).build() }
+	changed
+}
+
+/// `inspect_value!(ui, &mut value)` labels the control with the expression, keeping its id unique to the call site
+#[macro_export]
+macro_rules! inspect_value {
+	($ui:expr, $label:expr, $value:expr$(,)*) => { $crate::inspect_value($ui, $label, $value) };
+	($ui:expr, $value:expr$(,)*) => {
+		$crate::inspect_value($ui, &format!("{}##{}:{}:{}", stringify!($value).trim_start_matches("&mut").trim(), file!(), line!(), column!()), $value)
+	};
+}
+
 /// This holds internal state of inspector
 #[derive(Default)]
 pub struct InspectorState {
 	pub selected: Option<Entity>,
+	/// Entity under the mouse in the hierarchy window
+	pub hovered: Option<Entity>,
+	pub snap: SnapSettings,
+	/// Whether clicking in the game view selects what's under the cursor, needs the `EntityPicker` system
+	pub picking: bool,
+	/// Set by `TransformGizmo` while the mouse is on one of its handles, so clicks don't also pick
+	pub(crate) over_gizmo: bool,
+	/// Component copied from a header's context menu
+	pub clipboard: Option<Box<dyn std::any::Any + Send + Sync>>,
+	states: std::collections::HashMap<(Entity, std::any::TypeId), Box<dyn std::any::Any + Send + Sync>>,
+}
+
+impl InspectorState {
+	/// `Inspect::State` of a component on an entity, created on first use
+	pub fn state<'a, Component: Inspect<'a>>(&mut self, entity: Entity) -> &mut Component::State {
+		self.states
+			.entry((entity, std::any::TypeId::of::<Component>()))
+			.or_insert_with(|| Box::new(Component::State::default()))
+			.downcast_mut()
+			.unwrap_or_else(f!())
+	}
+
+	/// Drop the state of entities that no longer exist, done by the inspector system
+	pub fn forget_dead(&mut self, entities: &Entities<'_>) {
+		self.states.retain(|(entity, _), _| entities.is_alive(*entity));
+	}
 }
 
 /// Any component implementing Inspect and included in your `inspect!` will show up in the inspector
@@ -70,14 +146,18 @@ pub struct InspectorState {
 #[allow(unused_variables)]
 pub trait Inspect<'a>: Component {
 	type SystemData: SystemData<'a>;
+	/// UI state kept per entity, e.g. expanded nodes or search filters, `()` if there's none
+	type State: Default + Send + Sync + 'static;
 
 	/// This method is only ran if the component contains the selected entity
-	fn inspect(data: &mut Self::SystemData, entity: Entity) {}
+	fn inspect(data: &mut Self::SystemData, state: &mut Self::State, entity: Entity) {}
 	/// Decide if this component can be added (e.g. because it requires another component)
 	fn can_add(data: &mut Self::SystemData, entity: Entity) -> bool { false }
 	/// Decide if this component can be removed (e.g. because it's required by another component)
 	fn can_remove(data: &mut Self::SystemData, entity: Entity) -> bool { true }
 	fn add(data: &mut Self::SystemData, entity: Entity) {}
+	/// Copy for the header's "copy component" menu item, `None` leaves it out. `#[inspect(copy)]` makes the derive clone it
+	fn copy(&self) -> Option<Self> { None }
 	/// This method is ran on all entities, even if none are selected
 	fn setup(data: &mut Self::SystemData, entity: Option<Entity>) {}
 }
@@ -87,6 +167,7 @@ macro_rules! inspect_default {
 	($cmp: path) => {
 		impl<'a> $crate::Inspect<'a> for $cmp {
 			type SystemData = ::amethyst::ecs::Read<'a, ::amethyst::ecs::LazyUpdate>;
+			type State = ();
 
 			fn can_add(_: &mut Self::SystemData, _: ::amethyst::ecs::Entity) -> bool { true }
 			fn add(lazy: &mut Self::SystemData, entity: ::amethyst::ecs::Entity) { lazy.insert(entity, Self::default()); }
@@ -99,9 +180,32 @@ macro_rules! inspect_marker {
 	($cmp: path) => {
 		impl<'a> $crate::Inspect<'a> for $cmp {
 			type SystemData = ::amethyst::ecs::Read<'a, ::amethyst::ecs::LazyUpdate>;
+			type State = ();
 
 			fn can_add(_: &mut Self::SystemData, _: ::amethyst::ecs::Entity) -> bool { true }
 			fn add(lazy: &mut Self::SystemData, entity: ::amethyst::ecs::Entity) { lazy.insert(entity, Self); }
+			fn copy(&self) -> Option<Self> { Some(Self) }
+		}
+	};
+}
+
+/// Implements `InspectControl` for a bitflags-style type, listing the flags that get a checkbox
+/// ```ignore
+/// inspect_flags!(CollisionLayers { PLAYER, ENEMY, TERRAIN });
+/// ```
+#[macro_export]
+macro_rules! inspect_flags {
+	($flags: ty { $($flag:ident),+$(,)* }) => {
+		impl $crate::Flags for $flags {
+			fn flags() -> &'static [(&'static str, Self)] { &[$((stringify!($flag), <$flags>::$flag),)+] }
+			fn contains(&self, flag: Self) -> bool { <$flags>::contains(self, flag) }
+			fn set(&mut self, flag: Self, value: bool) { <$flags>::set(self, flag, value) }
+		}
+
+		impl<'control, 'resource: 'control> $crate::InspectControl<'control, 'resource> for &'control mut $flags {
+			type SystemData = ();
+			type State = ();
+			type Builder = $crate::FlagsControlBuilder<'control, $flags>;
 		}
 	};
 }
@@ -164,10 +268,31 @@ pub fn draw_inspect_component<'a, Component: Inspect<'a> + Send + Sync>(
 	store: &ReadStorage<'_, Component>,
 	entity: Entity,
 	lazy: &Read<'_, LazyUpdate>,
+	inspector_state: &mut InspectorState,
 ) {
 	if store.contains(entity) {
 		let mut remove = false;
 		let expanded = ui.collapsing_header(&imgui::im_str!("{}##header{:?}", name, entity)).flags(imgui::ImGuiTreeNodeFlags::AllowItemOverlap).default_open(true).build();
+		let popup = imgui::im_str!("{}##header_context", name);
+		if ui.is_item_hovered() && ui.is_mouse_clicked(imgui::MouseButton::Right) {
+			ui.open_popup(&popup);
+		}
+		let clipboard = &mut inspector_state.clipboard;
+		ui.popup(&popup, || {
+			// the clipboard keeps its own copy, pasting copies it again so it can be pasted more than once
+			let copy = store.get(entity).and_then(Component::copy);
+			if ui.menu_item(imgui::im_str!("copy component")).enabled(copy.is_some()).build() {
+				if let Some(copy) = copy {
+					*clipboard = Some(Box::new(copy));
+				}
+			}
+			let copied = clipboard.as_ref().and_then(|x| x.downcast_ref::<Component>()).and_then(Component::copy);
+			if ui.menu_item(imgui::im_str!("paste component")).enabled(copied.is_some()).build() {
+				if let Some(copied) = copied {
+					lazy.insert(entity, copied);
+				}
+			}
+		});
 		if Component::can_remove(cmp_data, entity) {
 			ui.same_line(0.);
 			remove = ui.small_button(&imgui::im_str!("remove##{}_header_remove", name));
@@ -175,11 +300,20 @@ pub fn draw_inspect_component<'a, Component: Inspect<'a> + Send + Sync>(
 		if remove {
 			lazy.remove::<Component>(entity);
 		} else if expanded {
-			Component::inspect(cmp_data, entity);
+			Component::inspect(cmp_data, inspector_state.state::<Component>(entity), entity);
 		}
 	}
 }
 
+#[doc(hidden)]
+pub fn draw_snap_settings(ui: &imgui::Ui, inspector_state: &mut InspectorState) {
+	if ui.collapsing_header(imgui::im_str!("snapping")).build() {
+		inspector_state.snap.draw(ui);
+		ui.separator();
+	}
+	inspector_state.snap.publish(ui);
+}
+
 #[macro_export]
 macro_rules! inspector {
 	($($cmp:ident),+$(,)*) => {
@@ -210,6 +344,9 @@ macro_rules! inspector {
 						imgui::Window::new(&im_str!("Inspector"))
 							.size([300.0, 500.0], imgui::Condition::FirstUseEver)
 							.build(ui, move || {
+								inspector_state.forget_dead(&entities);
+								$crate::draw_snap_settings(ui, &mut inspector_state);
+								ui.checkbox(im_str!("pick in game view"), &mut inspector_state.picking);
 								$(<$cmp as Inspect>::setup(&mut [<data $cmp>], inspector_state.selected);)+
 								if let Some(entity) = inspector_state.selected {
 									if entities.is_alive(entity) {
@@ -233,7 +370,7 @@ macro_rules! inspector {
 											ui.separator();
 										}
 
-										$($crate::draw_inspect_component(ui, stringify!($cmp), &mut [<data $cmp>], &[<store $cmp>], entity, &lazy);)+
+										$($crate::draw_inspect_component(ui, stringify!($cmp), &mut [<data $cmp>], &[<store $cmp>], entity, &lazy, &mut inspector_state);)+
 									}
 								}
 							});
@@ -0,0 +1,128 @@
+use amethyst::{
+	assets::AssetStorage,
+	core::{
+		math::{Point2, Point3, Vector2},
+		ParentHierarchy,
+		Transform,
+	},
+	ecs::prelude::*,
+	renderer::{debug_drawing::DebugLines, palette::Srgba, ActiveCamera, Camera, SpriteRender, SpriteSheet},
+	ui::UiTransform,
+	window::ScreenDimensions,
+};
+use crate::{gizmo::find_camera, picker::sprite_corners};
+
+/// The element's pixel rect in world space
+///
+/// UI isn't in the world, so the rect goes just in front of the camera where it lines up on screen.
+pub(crate) fn ui_bounds(ui_transform: &UiTransform, (camera, camera_transform): (&Camera, &Transform), screen: &ScreenDimensions) -> [Point3<f32>; 4] {
+	let diagonal = Vector2::new(screen.width(), screen.height());
+	let (x, y) = (ui_transform.pixel_x(), screen.height() - ui_transform.pixel_y());
+	let (w, h) = (ui_transform.pixel_width() / 2., ui_transform.pixel_height() / 2.);
+	let unproject = |x: f32, y: f32| {
+		let ray = camera.screen_ray(Point2::new(x, y), diagonal, camera_transform);
+		ray.origin + ray.direction.normalize()
+	};
+	[unproject(x - w, y - h), unproject(x + w, y - h), unproject(x + w, y + h), unproject(x - w, y + h)]
+}
+
+/// World space quads around the entity's sprite and UI rect, whichever it has
+pub(crate) fn bounds(
+	entity: Entity,
+	transforms: &ReadStorage<'_, Transform>,
+	sprites: &ReadStorage<'_, SpriteRender>,
+	ui_transforms: &ReadStorage<'_, UiTransform>,
+	sheets: &AssetStorage<SpriteSheet>,
+	camera: (&Camera, &Transform),
+	screen: &ScreenDimensions,
+) -> Vec<[Point3<f32>; 4]> {
+	let mut quads = Vec::new();
+
+	if let (Some(sprite_render), Some(transform)) = (sprites.get(entity), transforms.get(entity)) {
+		if let Some(corners) = sprite_corners(sprite_render, sheets) {
+			let matrix = transform.global_matrix();
+			quads.push([
+				matrix.transform_point(&corners[0]),
+				matrix.transform_point(&corners[1]),
+				matrix.transform_point(&corners[2]),
+				matrix.transform_point(&corners[3]),
+			]);
+		}
+	}
+
+	if let Some(ui_transform) = ui_transforms.get(entity) {
+		quads.push(ui_bounds(ui_transform, camera, screen));
+	}
+
+	quads
+}
+
+pub(crate) fn draw_quad(debug_lines: &mut DebugLines, quad: &[Point3<f32>; 4], color: [f32; 4]) {
+	let color = Srgba::new(color[0], color[1], color[2], color[3]);
+	for i in 0 .. 4 {
+		debug_lines.draw_line(quad[i], quad[(i + 1) % 4], color);
+	}
+}
+
+/// Outlines the selected entity's sprite and UI rect in the game view with `DebugLines`
+///
+/// Needs `RenderDebugLines` in the render bundle.
+pub struct SelectionOutline {
+	pub selected_color: [f32; 4],
+	/// Also outline every descendant of the selected entity
+	pub children: bool,
+	pub children_color: [f32; 4],
+	/// Also outline the entity under the mouse in the hierarchy window
+	pub hovered: bool,
+	pub hovered_color: [f32; 4],
+}
+
+impl Default for SelectionOutline {
+	fn default() -> Self {
+		Self {
+			selected_color: [1., 0.85, 0.2, 1.],
+			children: true,
+			children_color: [1., 0.85, 0.2, 0.35],
+			hovered: true,
+			hovered_color: [0.6, 0.8, 1., 0.75],
+		}
+	}
+}
+
+impl<'s> System<'s> for SelectionOutline {
+	type SystemData = (
+		Read<'s, crate::InspectorState>,
+		ReadExpect<'s, ParentHierarchy>,
+		ReadStorage<'s, Transform>,
+		ReadStorage<'s, SpriteRender>,
+		ReadStorage<'s, UiTransform>,
+		ReadStorage<'s, Camera>,
+		Read<'s, ActiveCamera>,
+		Read<'s, AssetStorage<SpriteSheet>>,
+		ReadExpect<'s, ScreenDimensions>,
+		Write<'s, DebugLines>,
+	);
+
+	fn run(&mut self, (inspector_state, hierarchy, transforms, sprites, ui_transforms, cameras, active_camera, sheets, screen, mut debug_lines): Self::SystemData) {
+		let camera = if let Some(x) = find_camera(&active_camera, &cameras, &transforms) { x } else { return; };
+		let mut outline = |entity: Entity, color: [f32; 4]| {
+			for quad in bounds(entity, &transforms, &sprites, &ui_transforms, &sheets, camera, &screen) {
+				draw_quad(&mut debug_lines, &quad, color);
+			}
+		};
+
+		if let Some(selected) = inspector_state.selected {
+			if self.children {
+				let mut descendants = hierarchy.children(selected).to_vec();
+				while let Some(child) = descendants.pop() {
+					outline(child, self.children_color);
+					descendants.extend_from_slice(hierarchy.children(child));
+				}
+			}
+			outline(selected, self.selected_color);
+		}
+		if let Some(hovered) = inspector_state.hovered.filter(|&x| self.hovered && Some(x) != inspector_state.selected) {
+			outline(hovered, self.hovered_color);
+		}
+	}
+}
@@ -0,0 +1,99 @@
+use amethyst::{
+	assets::AssetStorage,
+	core::{
+		math::{Point3, Vector2},
+		Hidden,
+		HiddenPropagate,
+		Transform,
+	},
+	ecs::prelude::*,
+	renderer::{ActiveCamera, Camera, SpriteRender, SpriteSheet},
+	ui::UiTransform,
+	window::ScreenDimensions,
+};
+use amethyst_imgui::imgui;
+use crate::gizmo::{find_camera, mouse_position};
+
+/// Selects the entity under the cursor when the game view is clicked while `InspectorState::picking` is on
+///
+/// Sprites are hit by their quad, UI elements by their pixel rect. Clicking the same spot again selects the next entity under it.
+#[derive(Default)]
+pub struct EntityPicker {
+	last_click: Option<Vector2<f32>>,
+}
+
+/// Corners of the sprite's quad in its entity's space, the same ones the sprite pass draws
+pub(crate) fn sprite_corners(sprite_render: &SpriteRender, sheets: &AssetStorage<SpriteSheet>) -> Option<[Point3<f32>; 4]> {
+	let sprite = sheets.get(&sprite_render.sprite_sheet)?.sprites.get(sprite_render.sprite_number)?;
+	let (x, y) = (-sprite.offsets[0], -sprite.offsets[1]);
+	let (w, h) = (sprite.width / 2., sprite.height / 2.);
+	Some([Point3::new(x - w, y - h, 0.), Point3::new(x + w, y - h, 0.), Point3::new(x + w, y + h, 0.), Point3::new(x - w, y + h, 0.)])
+}
+
+/// Whether `point` is inside the convex quad, whichever way it winds
+fn inside(point: Vector2<f32>, quad: &[Vector2<f32>; 4]) -> bool {
+	let sides = (0 .. 4).map(|i| {
+		let (a, b) = (quad[i], quad[(i + 1) % 4]);
+		(b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x)
+	}).collect::<Vec<_>>();
+	sides.iter().all(|&x| x >= 0.) || sides.iter().all(|&x| x <= 0.)
+}
+
+impl<'s> System<'s> for EntityPicker {
+	type SystemData = (
+		Write<'s, crate::InspectorState>,
+		Entities<'s>,
+		ReadStorage<'s, Transform>,
+		ReadStorage<'s, SpriteRender>,
+		ReadStorage<'s, UiTransform>,
+		ReadStorage<'s, Hidden>,
+		ReadStorage<'s, HiddenPropagate>,
+		ReadStorage<'s, Camera>,
+		Read<'s, ActiveCamera>,
+		Read<'s, AssetStorage<SpriteSheet>>,
+		ReadExpect<'s, ScreenDimensions>,
+	);
+
+	fn run(&mut self, (mut inspector_state, entities, transforms, sprites, ui_transforms, hidden, hidden_propagate, cameras, active_camera, sheets, screen): Self::SystemData) {
+		amethyst_imgui::with(|ui| {
+			if !inspector_state.picking || inspector_state.over_gizmo || ui.io().want_capture_mouse || !ui.is_mouse_clicked(imgui::MouseButton::Left) {
+				return;
+			}
+			let mouse = mouse_position(ui, &screen);
+
+			// everything under the cursor, topmost first: UI above sprites, then by depth
+			let mut under: Vec<(Entity, (u8, f32))> = Vec::new();
+			for (entity, ui_transform, _, _) in (&entities, &ui_transforms, !&hidden, !&hidden_propagate).join() {
+				// UI is laid out from the bottom left
+				let (x, y) = (mouse.x, screen.height() - mouse.y);
+				if (x - ui_transform.pixel_x()).abs() <= ui_transform.pixel_width() / 2. && (y - ui_transform.pixel_y()).abs() <= ui_transform.pixel_height() / 2. {
+					under.push((entity, (0, -ui_transform.global_z())));
+				}
+			}
+			if let Some((camera, camera_transform)) = find_camera(&active_camera, &cameras, &transforms) {
+				let diagonal = Vector2::new(screen.width(), screen.height());
+				let eye = camera_transform.global_matrix().column(3).xyz();
+				for (entity, sprite_render, transform, _, _) in (&entities, &sprites, &transforms, !&hidden, !&hidden_propagate).join() {
+					let corners = if let Some(x) = sprite_corners(sprite_render, &sheets) { x } else { continue; };
+					let matrix = transform.global_matrix();
+					let quad = [0, 1, 2, 3].iter().map(|&i| camera.world_to_screen(matrix.transform_point(&corners[i]), diagonal, camera_transform).coords).collect::<Vec<_>>();
+					if inside(mouse, &[quad[0], quad[1], quad[2], quad[3]]) {
+						under.push((entity, (1, (matrix.column(3).xyz() - eye).norm())));
+					}
+				}
+			}
+			under.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+			// clicking the same spot again digs down through whatever overlaps there
+			let again = self.last_click.map_or(false, |x| (x - mouse).norm() < 3.);
+			let next = match inspector_state.selected.and_then(|selected| under.iter().position(|&(x, _)| x == selected)) {
+				Some(i) if again => under.get((i + 1) % under.len()),
+				_ => under.first(),
+			};
+			if let Some(&(entity, _)) = next {
+				inspector_state.selected = Some(entity);
+			}
+			self.last_click = Some(mouse);
+		});
+	}
+}
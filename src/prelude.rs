@@ -2,7 +2,7 @@ pub use amethyst::{
 	prelude::*,
 	core::transform::Transform,
 	ecs::prelude::*,
-	core::math::{Vector2, Vector3, Vector4},
+	core::math::{Point2, Point3, Point4, Translation2, Translation3, Translation4, Vector2, Vector3, Vector4},
 };
 pub use amethyst_imgui::imgui::{self, im_str};
 pub use crate::{Inspect, InspectControl, InspectControlBuilder};
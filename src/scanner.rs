@@ -0,0 +1,104 @@
+use amethyst::{
+	assets::{AssetStorage, Loader},
+	ecs::prelude::*,
+	renderer::{ImageFormat, SpriteSheet, SpriteSheetFormat, Texture},
+	ui::{FontAsset, TtfFormat},
+};
+use crate::AssetList;
+use std::path::{Path, PathBuf};
+
+const TEXTURES: &[&str] = &["png", "jpg", "jpeg"];
+const SPRITE_SHEETS: &[&str] = &["ron"];
+const FONTS: &[&str] = &["ttf", "otf"];
+
+/// Opt-in system that fills `TextureList`, `SpriteList` and `FontList` from a directory under the asset root
+///
+/// Nothing is loaded until a picker for that kind of asset is opened. Handles are keyed by their path relative to
+/// the asset root, the same path you'd give the `Loader`. Each list is scanned once, see `AssetList::rescan`.
+///
+/// Only a `foo.ron` with a texture `foo.png` (or `.jpg`, `.jpeg`) next to it is taken for a sprite sheet, the one it's
+/// loaded with. Other `.ron` files, prefabs and configs, are skipped.
+pub struct AssetScanner {
+	root: PathBuf,
+	directory: PathBuf,
+}
+
+impl AssetScanner {
+	/// `root` is the asset directory given to amethyst, `directory` is relative to it
+	pub fn new(root: impl Into<PathBuf>, directory: impl Into<PathBuf>) -> Self {
+		Self { root: root.into(), directory: directory.into() }
+	}
+
+	fn scan(&self, extensions: &[&str]) -> Vec<String> {
+		fn walk(dir: &Path, out: &mut Vec<PathBuf>) {
+			let entries = if let Ok(x) = std::fs::read_dir(dir) { x } else { return; };
+			for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+				if path.is_dir() {
+					walk(&path, out);
+				} else {
+					out.push(path);
+				}
+			}
+		}
+
+		let mut files = Vec::new();
+		walk(&self.root.join(&self.directory), &mut files);
+
+		let mut found = files
+			.into_iter()
+			.filter(|path| path.extension().and_then(|x| x.to_str()).map_or(false, |x| extensions.contains(&x.to_lowercase().as_str())))
+			.filter_map(|path| path.strip_prefix(&self.root).ok().map(|x| x.to_string_lossy().replace('\\', "/")))
+			.collect::<Vec<_>>();
+		found.sort();
+		found
+	}
+}
+
+impl<'s> System<'s> for AssetScanner {
+	type SystemData = (
+		ReadExpect<'s, Loader>,
+		Write<'s, AssetList<Texture>>,
+		Write<'s, AssetList<SpriteSheet>>,
+		Write<'s, AssetList<FontAsset>>,
+		Read<'s, AssetStorage<Texture>>,
+		Read<'s, AssetStorage<SpriteSheet>>,
+		Read<'s, AssetStorage<FontAsset>>,
+	);
+
+	fn run(&mut self, (loader, mut textures, mut sprite_sheets, mut fonts, texture_storage, sprite_sheet_storage, font_storage): Self::SystemData) {
+		// sprite sheets need their textures so they pull the texture list along
+		let want_sprite_sheets = sprite_sheets.take_request();
+		let want_textures = textures.take_request() || want_sprite_sheets;
+
+		if want_textures {
+			for path in self.scan(TEXTURES) {
+				if !textures.contains_key(&path) {
+					let handle = loader.load(path.as_str(), ImageFormat::default(), (), &texture_storage);
+					textures.insert(path, handle);
+				}
+			}
+		}
+
+		if want_sprite_sheets {
+			for path in self.scan(SPRITE_SHEETS) {
+				if sprite_sheets.contains_key(&path) {
+					continue;
+				}
+				let texture = TEXTURES.iter().map(|ext| Path::new(&path).with_extension(ext).to_string_lossy().into_owned()).find_map(|x| textures.get(&x).cloned());
+				if let Some(texture) = texture {
+					let handle = loader.load(path.as_str(), SpriteSheetFormat(texture), (), &sprite_sheet_storage);
+					sprite_sheets.insert(path, handle);
+				}
+			}
+		}
+
+		if fonts.take_request() {
+			for path in self.scan(FONTS) {
+				if !fonts.contains_key(&path) {
+					let handle = loader.load(path.as_str(), TtfFormat, (), &font_storage);
+					fonts.insert(path, handle);
+				}
+			}
+		}
+	}
+}